@@ -13,6 +13,6 @@ fn main() {
 
     let image = imread(&args[1], ImageReadMode::Color).expect("Failed to read from path");
     let window = Window::new("Display", WindowFlag::Normal).expect("Failed to open window");
-    window.show(&image);
-    window.wait_key(0);
+    window.show(&image).expect("Failed to show image");
+    window.wait_key(0).expect("Failed to wait for a key");
 }
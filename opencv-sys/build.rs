@@ -30,6 +30,12 @@ fn source(module: &str) -> String {
     path
 }
 
+fn ext_source(module: &str) -> String {
+    let mut path = String::from(module);
+    path += "_ext.cpp";
+    path
+}
+
 fn generate_binding() {
     let out_path = PathBuf::from(env::var("OUT_DIR").unwrap());
 
@@ -57,7 +63,20 @@ fn main() {
         "videoio",
     ];
 
+    let ext_modules = vec![
+        "core",
+        "dnn",
+        "features2d",
+        "highgui",
+        "imgcodecs",
+        "imgproc",
+        "objdetect",
+        "video",
+        "videoio",
+    ];
+
     let mut sources: Vec<String> = modules.iter().map(|m| source(m)).collect();
+    sources.extend(ext_modules.iter().map(|m| ext_source(m)));
 
     if cfg!(feature = "cuda") {
         sources.push("cuda.cpp".to_string());
@@ -0,0 +1,749 @@
+#include <cstring>
+#include <new>
+#include "core_ext.h"
+
+// Reported instead of a string that could not be copied, so that building a
+// status never throws. CvStatus_Close does not free it.
+static char kOutOfMemory[] = "out of memory while copying the error message";
+
+static char* CopyString(const char* s) {
+    char* copy = new (std::nothrow) char[std::strlen(s) + 1];
+    if (copy == nullptr) {
+        return kOutOfMemory;
+    }
+    std::strcpy(copy, s);
+    return copy;
+}
+
+static void FreeString(char* s) {
+    if (s != kOutOfMemory) {
+        delete[] s;
+    }
+}
+
+CvStatus CvStatus_Ok() {
+    CvStatus status = {0, nullptr, nullptr, nullptr, 0};
+    return status;
+}
+
+CvStatus CvStatus_FromException(const cv::Exception& e) {
+    CvStatus status = {
+        e.code, CopyString(e.err.c_str()), CopyString(e.func.c_str()), CopyString(e.file.c_str()), e.line};
+    return status;
+}
+
+CvStatus CvStatus_FromMessage(int code, const char* err) {
+    CvStatus status = {code, CopyString(err), CopyString(""), CopyString(""), 0};
+    return status;
+}
+
+void CvStatus_Close(CvStatus status) {
+    FreeString(status.err);
+    FreeString(status.func);
+    FreeString(status.file);
+}
+
+CvStatus Mat_ToPoints2f(Mat m, Points2f pts) {
+    CV_TRY
+    cv::Mat points;
+    m->reshape(2, pts.length).convertTo(points, CV_32F);
+    for (int i = 0; i < pts.length; ++i) {
+        cv::Point2f p = points.at<cv::Point2f>(i);
+        pts.points[i] = Point2f{p.x, p.y};
+    }
+    CV_CATCH
+}
+
+unsigned char* Mat_Data(Mat m) {
+    return m->data;
+}
+
+int Mat_Total(Mat m) {
+    return (int)m->total();
+}
+
+bool Mat_IsContinuous(Mat m) {
+    return m->isContinuous();
+}
+
+int Mat_Dims(Mat m) {
+    return m->dims;
+}
+
+int Mat_SizeAt(Mat m, int i) {
+    return m->size[i];
+}
+
+CvStatus Mat_NewWithSize_Checked(int rows, int cols, int type, Mat* result) {
+    CV_TRY
+    *result = Mat_NewWithSize(rows, cols, type);
+    CV_CATCH
+}
+
+CvStatus Mat_NewFromScalar_Checked(Scalar ar, int type, Mat* result) {
+    CV_TRY
+    *result = Mat_NewFromScalar(ar, type);
+    CV_CATCH
+}
+
+CvStatus Mat_NewFromBytes_Checked(int rows, int cols, int type, ByteArray buf, Mat* result) {
+    CV_TRY
+    CV_Assert(rows >= 0 && cols >= 0 && buf.length >= 0);
+    CV_Assert(static_cast<size_t>(buf.length) >= static_cast<size_t>(rows) * cols * CV_ELEM_SIZE(type));
+    // The data is copied, so that the Mat does not alias the caller's buffer.
+    *result = new cv::Mat(cv::Mat(rows, cols, type, buf.data).clone());
+    CV_CATCH
+}
+
+CvStatus Mat_CopyTo_Checked(Mat m, Mat dst) {
+    CV_TRY
+    Mat_CopyTo(m, dst);
+    CV_CATCH
+}
+
+CvStatus Mat_CopyToWithMask_Checked(Mat m, Mat dst, Mat mask) {
+    CV_TRY
+    Mat_CopyToWithMask(m, dst, mask);
+    CV_CATCH
+}
+
+CvStatus Mat_ConvertTo_Checked(Mat m, Mat dst, int type) {
+    CV_TRY
+    Mat_ConvertTo(m, dst, type);
+    CV_CATCH
+}
+
+CvStatus Mat_Region_Checked(Mat m, Rect r, Mat* result) {
+    CV_TRY
+    *result = Mat_Region(m, r);
+    CV_CATCH
+}
+
+CvStatus Mat_Reshape_Checked(Mat m, int cn, int rows, Mat* result) {
+    CV_TRY
+    *result = Mat_Reshape(m, cn, rows);
+    CV_CATCH
+}
+
+CvStatus Mat_ConvertFp16_Checked(Mat m, Mat* result) {
+    CV_TRY
+    *result = Mat_ConvertFp16(m);
+    CV_CATCH
+}
+
+CvStatus Mat_Mean_Checked(Mat m, Scalar* result) {
+    CV_TRY
+    *result = Mat_Mean(m);
+    CV_CATCH
+}
+
+CvStatus Mat_Sum_Checked(Mat m, Scalar* result) {
+    CV_TRY
+    *result = Mat_Sum(m);
+    CV_CATCH
+}
+
+CvStatus LUT_Checked(Mat src, Mat lut, Mat dst) {
+    CV_TRY
+    LUT(src, lut, dst);
+    CV_CATCH
+}
+
+CvStatus Mat_AbsDiff_Checked(Mat src1, Mat src2, Mat dst) {
+    CV_TRY
+    Mat_AbsDiff(src1, src2, dst);
+    CV_CATCH
+}
+
+CvStatus Mat_Add_Checked(Mat src1, Mat src2, Mat dst, Mat mask, int dtype) {
+    CV_TRY
+    cv::add(*src1, *src2, *dst, ToInputArray(mask), dtype);
+    CV_CATCH
+}
+
+CvStatus Mat_AddWeighted_Checked(Mat src1, double alpha, Mat src2, double beta, double gamma, Mat dst) {
+    CV_TRY
+    Mat_AddWeighted(src1, alpha, src2, beta, gamma, dst);
+    CV_CATCH
+}
+
+CvStatus Mat_BitwiseAnd_Checked(Mat src1, Mat src2, Mat dst) {
+    CV_TRY
+    Mat_BitwiseAnd(src1, src2, dst);
+    CV_CATCH
+}
+
+CvStatus Mat_BitwiseNot_Checked(Mat src1, Mat dst) {
+    CV_TRY
+    Mat_BitwiseNot(src1, dst);
+    CV_CATCH
+}
+
+CvStatus Mat_BitwiseOr_Checked(Mat src1, Mat src2, Mat dst) {
+    CV_TRY
+    Mat_BitwiseOr(src1, src2, dst);
+    CV_CATCH
+}
+
+CvStatus Mat_BitwiseXor_Checked(Mat src1, Mat src2, Mat dst) {
+    CV_TRY
+    Mat_BitwiseXor(src1, src2, dst);
+    CV_CATCH
+}
+
+CvStatus Mat_BatchDistance_Checked(Mat src1,
+                                   Mat src2,
+                                   Mat dist,
+                                   int dtype,
+                                   Mat nidx,
+                                   int normType,
+                                   int K,
+                                   Mat mask,
+                                   int update,
+                                   bool crosscheck) {
+    CV_TRY
+    Mat_BatchDistance(src1, src2, dist, dtype, nidx, normType, K, mask, update, crosscheck);
+    CV_CATCH
+}
+
+CvStatus Mat_BorderInterpolate_Checked(int p, int len, int borderType, int* result) {
+    CV_TRY
+    *result = Mat_BorderInterpolate(p, len, borderType);
+    CV_CATCH
+}
+
+CvStatus Mat_CalcCovarMatrix_Checked(Mat samples, Mat covar, Mat mean, int flags, int ctype) {
+    CV_TRY
+    Mat_CalcCovarMatrix(samples, covar, mean, flags, ctype);
+    CV_CATCH
+}
+
+CvStatus Mat_CartToPolar_Checked(Mat x, Mat y, Mat magnitude, Mat angle, bool angleInDegrees) {
+    CV_TRY
+    Mat_CartToPolar(x, y, magnitude, angle, angleInDegrees);
+    CV_CATCH
+}
+
+CvStatus Mat_Compare_Checked(Mat src1, Mat src2, Mat dst, int ct) {
+    CV_TRY
+    Mat_Compare(src1, src2, dst, ct);
+    CV_CATCH
+}
+
+CvStatus Mat_CountNonZero_Checked(Mat src, int* result) {
+    CV_TRY
+    *result = Mat_CountNonZero(src);
+    CV_CATCH
+}
+
+CvStatus Mat_CompleteSymm_Checked(Mat m, bool lowerToUpper) {
+    CV_TRY
+    Mat_CompleteSymm(m, lowerToUpper);
+    CV_CATCH
+}
+
+CvStatus Mat_ConvertScaleAbs_Checked(Mat src, Mat dst, double alpha, double beta) {
+    CV_TRY
+    Mat_ConvertScaleAbs(src, dst, alpha, beta);
+    CV_CATCH
+}
+
+CvStatus Mat_CopyMakeBorder_Checked(
+    Mat src, Mat dst, int top, int bottom, int left, int right, int borderType, Scalar value) {
+    CV_TRY
+    Mat_CopyMakeBorder(src, dst, top, bottom, left, right, borderType, value);
+    CV_CATCH
+}
+
+CvStatus Mat_MinMaxLoc_Checked(Mat m, double* minVal, double* maxVal, Point* minLoc, Point* maxLoc) {
+    CV_TRY
+    Mat_MinMaxLoc(m, minVal, maxVal, minLoc, maxLoc);
+    CV_CATCH
+}
+
+static cv::Scalar ToCvScalar(Scalar s) {
+    return cv::Scalar(s.val1, s.val2, s.val3, s.val4);
+}
+
+static Scalar FromCvScalar(const cv::Scalar& s) {
+    return Scalar{s.val[0], s.val[1], s.val[2], s.val[3]};
+}
+
+CvStatus Mat_Subtract_Checked(Mat src1, Mat src2, Mat dst, Mat mask, int dtype) {
+    CV_TRY
+    cv::subtract(*src1, *src2, *dst, ToInputArray(mask), dtype);
+    CV_CATCH
+}
+
+CvStatus Mat_Multiply_Checked(Mat src1, Mat src2, Mat dst, double scale, int dtype) {
+    CV_TRY
+    cv::multiply(*src1, *src2, *dst, scale, dtype);
+    CV_CATCH
+}
+
+CvStatus Mat_Divide_Checked(Mat src1, Mat src2, Mat dst, double scale, int dtype) {
+    CV_TRY
+    cv::divide(*src1, *src2, *dst, scale, dtype);
+    CV_CATCH
+}
+
+CvStatus Mat_ScaleAdd_Checked(Mat src1, double alpha, Mat src2, Mat dst) {
+    CV_TRY
+    cv::scaleAdd(*src1, alpha, *src2, *dst);
+    CV_CATCH
+}
+
+CvStatus Mat_Pow_Checked(Mat src, double power, Mat dst) {
+    CV_TRY
+    cv::pow(*src, power, *dst);
+    CV_CATCH
+}
+
+CvStatus Mat_Sqrt_Checked(Mat src, Mat dst) {
+    CV_TRY
+    cv::sqrt(*src, *dst);
+    CV_CATCH
+}
+
+CvStatus Mat_Exp_Checked(Mat src, Mat dst) {
+    CV_TRY
+    cv::exp(*src, *dst);
+    CV_CATCH
+}
+
+CvStatus Mat_Log_Checked(Mat src, Mat dst) {
+    CV_TRY
+    cv::log(*src, *dst);
+    CV_CATCH
+}
+
+CvStatus Mat_Magnitude_Checked(Mat x, Mat y, Mat magnitude) {
+    CV_TRY
+    cv::magnitude(*x, *y, *magnitude);
+    CV_CATCH
+}
+
+CvStatus Mat_Phase_Checked(Mat x, Mat y, Mat angle, bool angleInDegrees) {
+    CV_TRY
+    cv::phase(*x, *y, *angle, angleInDegrees);
+    CV_CATCH
+}
+
+CvStatus Mat_PolarToCart_Checked(Mat magnitude, Mat angle, Mat x, Mat y, bool angleInDegrees) {
+    CV_TRY
+    cv::polarToCart(*magnitude, *angle, *x, *y, angleInDegrees);
+    CV_CATCH
+}
+
+CvStatus Mat_Min_Checked(Mat src1, Mat src2, Mat dst) {
+    CV_TRY
+    cv::min(*src1, *src2, *dst);
+    CV_CATCH
+}
+
+CvStatus Mat_Max_Checked(Mat src1, Mat src2, Mat dst) {
+    CV_TRY
+    cv::max(*src1, *src2, *dst);
+    CV_CATCH
+}
+
+CvStatus Mat_Abs_Checked(Mat src, Mat dst) {
+    CV_TRY
+    *dst = cv::abs(*src);
+    CV_CATCH
+}
+
+CvStatus Mat_AddScalar_Checked(Mat src, Scalar value, Mat dst) {
+    CV_TRY
+    cv::add(*src, ToCvScalar(value), *dst);
+    CV_CATCH
+}
+
+CvStatus Mat_SubtractScalar_Checked(Mat src, Scalar value, Mat dst) {
+    CV_TRY
+    cv::subtract(*src, ToCvScalar(value), *dst);
+    CV_CATCH
+}
+
+CvStatus Mat_SubtractFromScalar_Checked(Scalar value, Mat src, Mat dst) {
+    CV_TRY
+    cv::subtract(ToCvScalar(value), *src, *dst);
+    CV_CATCH
+}
+
+CvStatus Mat_MultiplyScalar_Checked(Mat src, Scalar value, Mat dst) {
+    CV_TRY
+    cv::multiply(*src, ToCvScalar(value), *dst);
+    CV_CATCH
+}
+
+CvStatus Mat_DivideScalar_Checked(Mat src, Scalar value, Mat dst) {
+    CV_TRY
+    cv::divide(*src, ToCvScalar(value), *dst);
+    CV_CATCH
+}
+
+CvStatus Mat_Scale_Checked(Mat src, double alpha, Mat dst) {
+    CV_TRY
+    src->convertTo(*dst, -1, alpha);
+    CV_CATCH
+}
+
+CvStatus Mat_BitwiseAndScalar_Checked(Mat src, Scalar value, Mat dst) {
+    CV_TRY
+    cv::bitwise_and(*src, ToCvScalar(value), *dst);
+    CV_CATCH
+}
+
+CvStatus Mat_BitwiseOrScalar_Checked(Mat src, Scalar value, Mat dst) {
+    CV_TRY
+    cv::bitwise_or(*src, ToCvScalar(value), *dst);
+    CV_CATCH
+}
+
+CvStatus Mat_BitwiseXorScalar_Checked(Mat src, Scalar value, Mat dst) {
+    CV_TRY
+    cv::bitwise_xor(*src, ToCvScalar(value), *dst);
+    CV_CATCH
+}
+
+CvStatus Mat_DFT_Checked(Mat src, Mat dst, int flags, int nonzeroRows) {
+    CV_TRY
+    cv::dft(*src, *dst, flags, nonzeroRows);
+    CV_CATCH
+}
+
+CvStatus Mat_IDFT_Checked(Mat src, Mat dst, int flags, int nonzeroRows) {
+    CV_TRY
+    cv::idft(*src, *dst, flags, nonzeroRows);
+    CV_CATCH
+}
+
+CvStatus Mat_DCT_Checked(Mat src, Mat dst, int flags) {
+    CV_TRY
+    cv::dct(*src, *dst, flags);
+    CV_CATCH
+}
+
+CvStatus Mat_IDCT_Checked(Mat src, Mat dst, int flags) {
+    CV_TRY
+    cv::idct(*src, *dst, flags);
+    CV_CATCH
+}
+
+CvStatus Mat_MulSpectrums_Checked(Mat a, Mat b, Mat dst, int flags, bool conjB) {
+    CV_TRY
+    cv::mulSpectrums(*a, *b, *dst, flags, conjB);
+    CV_CATCH
+}
+
+int GetOptimalDFTSize(int vecsize) {
+    return cv::getOptimalDFTSize(vecsize);
+}
+
+static std::vector<cv::Mat> ToMatVector(MatArray mats) {
+    std::vector<cv::Mat> result;
+    for (int i = 0; i < mats.length; ++i) {
+        result.push_back(*mats.mats[i]);
+    }
+    return result;
+}
+
+CvStatus Mat_Split_Checked(Mat src, MatArray result) {
+    CV_TRY
+    std::vector<cv::Mat> channels;
+    cv::split(*src, channels);
+    CV_Assert((int)channels.size() == result.length);
+    for (int i = 0; i < result.length; ++i) {
+        *result.mats[i] = channels[i];
+    }
+    CV_CATCH
+}
+
+CvStatus Mat_Merge_Checked(MatArray mats, Mat dst) {
+    CV_TRY
+    cv::merge(ToMatVector(mats), *dst);
+    CV_CATCH
+}
+
+CvStatus Mat_MixChannels_Checked(MatArray src, MatArray dst, const int* fromTo, int npairs) {
+    CV_TRY
+    // The headers share their data with the destination Mats, which
+    // mixChannels requires to be allocated already.
+    std::vector<cv::Mat> dst_mats = ToMatVector(dst);
+    cv::mixChannels(ToMatVector(src), dst_mats, fromTo, npairs);
+    CV_CATCH
+}
+
+CvStatus Mat_ExtractChannel_Checked(Mat src, Mat dst, int coi) {
+    CV_TRY
+    cv::extractChannel(*src, *dst, coi);
+    CV_CATCH
+}
+
+CvStatus Mat_InsertChannel_Checked(Mat src, Mat dst, int coi) {
+    CV_TRY
+    cv::insertChannel(*src, *dst, coi);
+    CV_CATCH
+}
+
+CvStatus Mat_Flip_Checked(Mat src, Mat dst, int flipCode) {
+    CV_TRY
+    cv::flip(*src, *dst, flipCode);
+    CV_CATCH
+}
+
+CvStatus Mat_Rotate_Checked(Mat src, Mat dst, int rotateCode) {
+    CV_TRY
+    cv::rotate(*src, *dst, rotateCode);
+    CV_CATCH
+}
+
+CvStatus Mat_Repeat_Checked(Mat src, int ny, int nx, Mat dst) {
+    CV_TRY
+    cv::repeat(*src, ny, nx, *dst);
+    CV_CATCH
+}
+
+CvStatus Mat_Hconcat_Checked(MatArray mats, Mat dst) {
+    CV_TRY
+    cv::hconcat(ToMatVector(mats), *dst);
+    CV_CATCH
+}
+
+CvStatus Mat_Vconcat_Checked(MatArray mats, Mat dst) {
+    CV_TRY
+    cv::vconcat(ToMatVector(mats), *dst);
+    CV_CATCH
+}
+
+CvStatus Mat_MeanWithMask_Checked(Mat m, Mat mask, Scalar* result) {
+    CV_TRY
+    *result = FromCvScalar(cv::mean(*m, *mask));
+    CV_CATCH
+}
+
+CvStatus Mat_MeanStdDev_Checked(Mat src, Mat mask, Scalar* mean, Scalar* stddev) {
+    CV_TRY
+    cv::Scalar cv_mean, cv_stddev;
+    cv::meanStdDev(*src, cv_mean, cv_stddev, ToInputArray(mask));
+    *mean = FromCvScalar(cv_mean);
+    *stddev = FromCvScalar(cv_stddev);
+    CV_CATCH
+}
+
+CvStatus Mat_MinMaxLocWithMask_Checked(Mat m, Mat mask, double* minVal, double* maxVal, Point* minLoc, Point* maxLoc) {
+    CV_TRY
+    cv::Point cv_min_loc, cv_max_loc;
+    cv::minMaxLoc(*m, minVal, maxVal, &cv_min_loc, &cv_max_loc, *mask);
+    *minLoc = Point{cv_min_loc.x, cv_min_loc.y};
+    *maxLoc = Point{cv_max_loc.x, cv_max_loc.y};
+    CV_CATCH
+}
+
+CvStatus Mat_MinMaxIdx_Checked(Mat src, Mat mask, double* minVal, double* maxVal, int* minIdx, int* maxIdx) {
+    CV_TRY
+    cv::minMaxIdx(*src, minVal, maxVal, minIdx, maxIdx, ToInputArray(mask));
+    CV_CATCH
+}
+
+CvStatus Mat_Norm_Checked(Mat src, int normType, Mat mask, double* result) {
+    CV_TRY
+    *result = cv::norm(*src, normType, ToInputArray(mask));
+    CV_CATCH
+}
+
+CvStatus Mat_NormDiff_Checked(Mat src1, Mat src2, int normType, Mat mask, double* result) {
+    CV_TRY
+    *result = cv::norm(*src1, *src2, normType, ToInputArray(mask));
+    CV_CATCH
+}
+
+CvStatus Mat_InRange_Checked(Mat src, Scalar lower, Scalar upper, Mat dst) {
+    CV_TRY
+    cv::inRange(*src, ToCvScalar(lower), ToCvScalar(upper), *dst);
+    CV_CATCH
+}
+
+CvStatus Mat_Normalize_Checked(Mat src, Mat dst, double alpha, double beta, int normType, int dtype, Mat mask) {
+    CV_TRY
+    cv::normalize(*src, *dst, alpha, beta, normType, dtype, ToInputArray(mask));
+    CV_CATCH
+}
+
+CvStatus Mat_Reduce_Checked(Mat src, Mat dst, int dim, int rtype, int dtype) {
+    CV_TRY
+    cv::reduce(*src, *dst, dim, rtype, dtype);
+    CV_CATCH
+}
+
+CvStatus Mat_Gemm_Checked(Mat src1, Mat src2, double alpha, Mat src3, double beta, Mat dst, int flags) {
+    CV_TRY
+    cv::gemm(*src1, *src2, alpha, ToInputArray(src3), beta, *dst, flags);
+    CV_CATCH
+}
+
+CvStatus Mat_Transpose_Checked(Mat src, Mat dst) {
+    CV_TRY
+    cv::transpose(*src, *dst);
+    CV_CATCH
+}
+
+CvStatus Mat_Invert_Checked(Mat src, Mat dst, int flags, double* result) {
+    CV_TRY
+    *result = cv::invert(*src, *dst, flags);
+    CV_CATCH
+}
+
+CvStatus Mat_Solve_Checked(Mat src1, Mat src2, Mat dst, int flags, bool* result) {
+    CV_TRY
+    *result = cv::solve(*src1, *src2, *dst, flags);
+    CV_CATCH
+}
+
+CvStatus Mat_Determinant_Checked(Mat m, double* result) {
+    CV_TRY
+    *result = cv::determinant(*m);
+    CV_CATCH
+}
+
+CvStatus Mat_Trace_Checked(Mat m, Scalar* result) {
+    CV_TRY
+    *result = FromCvScalar(cv::trace(*m));
+    CV_CATCH
+}
+
+CvStatus SVD_Compute_Checked(Mat src, Mat w, Mat u, Mat vt, int flags) {
+    CV_TRY
+    cv::SVD::compute(*src, *w, *u, *vt, flags);
+    CV_CATCH
+}
+
+CvStatus SVD_BackSubst_Checked(Mat w, Mat u, Mat vt, Mat rhs, Mat dst) {
+    CV_TRY
+    cv::SVD::backSubst(*w, *u, *vt, *rhs, *dst);
+    CV_CATCH
+}
+
+CvStatus Mat_Eigen_Checked(Mat src, Mat eigenvalues, Mat eigenvectors, bool* result) {
+    CV_TRY
+    *result = cv::eigen(*src, *eigenvalues, *eigenvectors);
+    CV_CATCH
+}
+
+CvStatus Mat_MulTransposed_Checked(Mat src, Mat dst, bool aTa, Mat delta, double scale, int dtype) {
+    CV_TRY
+    cv::mulTransposed(*src, *dst, aTa, ToInputArray(delta), scale, dtype);
+    CV_CATCH
+}
+
+CvStatus Mat_Cross_Checked(Mat a, Mat b, Mat dst) {
+    CV_TRY
+    a->cross(*b).copyTo(*dst);
+    CV_CATCH
+}
+
+CvStatus Mat_Dot_Checked(Mat a, Mat b, double* result) {
+    CV_TRY
+    *result = a->dot(*b);
+    CV_CATCH
+}
+
+CvStatus PCA_New(Mat data, Mat mean, int flags, int maxComponents, PCA* result) {
+    CV_TRY
+    *result = new cv::PCA(*data, ToInputArray(mean), flags, maxComponents);
+    CV_CATCH
+}
+
+CvStatus PCA_NewWithVariance(Mat data, Mat mean, int flags, double retainedVariance, PCA* result) {
+    CV_TRY
+    *result = new cv::PCA(*data, ToInputArray(mean), flags, retainedVariance);
+    CV_CATCH
+}
+
+void PCA_Close(PCA pca) {
+    delete pca;
+}
+
+CvStatus PCA_Project(PCA pca, Mat vec, Mat result) {
+    CV_TRY
+    pca->project(*vec, *result);
+    CV_CATCH
+}
+
+CvStatus PCA_BackProject(PCA pca, Mat vec, Mat result) {
+    CV_TRY
+    pca->backProject(*vec, *result);
+    CV_CATCH
+}
+
+Mat PCA_GetMean(PCA pca) {
+    return new cv::Mat(pca->mean);
+}
+
+Mat PCA_GetEigenvalues(PCA pca) {
+    return new cv::Mat(pca->eigenvalues);
+}
+
+Mat PCA_GetEigenvectors(PCA pca) {
+    return new cv::Mat(pca->eigenvectors);
+}
+
+RNG RNG_New(uint64_t state) {
+    return new cv::RNG(state);
+}
+
+void RNG_Close(RNG rng) {
+    delete rng;
+}
+
+unsigned int RNG_Next(RNG rng) {
+    return rng->next();
+}
+
+int RNG_UniformInt(RNG rng, int a, int b) {
+    return rng->uniform(a, b);
+}
+
+double RNG_UniformDouble(RNG rng, double a, double b) {
+    return rng->uniform(a, b);
+}
+
+double RNG_Gaussian(RNG rng, double sigma) {
+    return rng->gaussian(sigma);
+}
+
+CvStatus RNG_Fill(RNG rng, Mat mat, int distType, Scalar a, Scalar b) {
+    CV_TRY
+    rng->fill(*mat, distType, ToCvScalar(a), ToCvScalar(b));
+    CV_CATCH
+}
+
+CvStatus Mat_RandU_Checked(Mat dst, Scalar low, Scalar high) {
+    CV_TRY
+    cv::randu(*dst, ToCvScalar(low), ToCvScalar(high));
+    CV_CATCH
+}
+
+CvStatus Mat_RandN_Checked(Mat dst, Scalar mean, Scalar stddev) {
+    CV_TRY
+    cv::randn(*dst, ToCvScalar(mean), ToCvScalar(stddev));
+    CV_CATCH
+}
+
+CvStatus Mat_RandShuffle_Checked(Mat dst, double iterFactor, RNG rng) {
+    CV_TRY
+    cv::randShuffle(*dst, iterFactor, rng);
+    CV_CATCH
+}
+
+void SetRNGSeed(int seed) {
+    cv::setRNGSeed(seed);
+}
+
+CvStatus Mat_KMeans_Checked(Mat data, int k, Mat bestLabels, TermCriteria criteria, int attempts, int flags,
+                            Mat centers, double* compactness) {
+    CV_TRY
+    *compactness = cv::kmeans(*data, k, *bestLabels, ToCvTermCriteria(criteria), attempts, flags, *centers);
+    CV_CATCH
+}
@@ -0,0 +1,294 @@
+#ifndef _OPENCV3_CORE_EXT_H_
+#define _OPENCV3_CORE_EXT_H_
+
+#include <stdbool.h>
+#include <stdint.h>
+#include "gocv/core.h"
+
+#ifdef __cplusplus
+typedef cv::PCA* PCA;
+typedef cv::RNG* RNG;
+#else
+typedef void* PCA;
+typedef void* RNG;
+#endif
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+// Outcome of a call that may raise a cv::Exception. A zero code means that
+// the call succeeded and all strings are NULL; otherwise the strings are owned
+// by the status and must be released with CvStatus_Close.
+typedef struct CvStatus {
+    int code;
+    char* err;
+    char* func;
+    char* file;
+    int line;
+} CvStatus;
+
+void CvStatus_Close(CvStatus status);
+
+// Wrapper for an individual cv::Point2f
+typedef struct Point2f {
+    float x;
+    float y;
+} Point2f;
+
+// Wrapper for the vector of Point2f structs aka std::vector<Point2f>
+typedef struct Points2f {
+    Point2f* points;
+    int length;
+} Points2f;
+
+// Wrapper for an individual cv::Size2f
+typedef struct Size2f {
+    float width;
+    float height;
+} Size2f;
+
+// Wrapper for an individual cv::RotatedRect
+typedef struct RotatedRect2f {
+    Point2f center;
+    Size2f size;
+    float angle;
+} RotatedRect2f;
+
+// Wrapper for a list of Mats owned by the caller, aka std::vector<cv::Mat>
+typedef struct MatArray {
+    Mat* mats;
+    int length;
+} MatArray;
+
+// Wrapper for an individual cv::TermCriteria
+typedef struct TermCriteria {
+    int type;
+    int maxCount;
+    double epsilon;
+} TermCriteria;
+
+CvStatus Mat_ToPoints2f(Mat m, Points2f pts);
+
+unsigned char* Mat_Data(Mat m);
+int Mat_Total(Mat m);
+bool Mat_IsContinuous(Mat m);
+int Mat_Dims(Mat m);
+int Mat_SizeAt(Mat m, int i);
+
+// =============================================================================
+//   Exception-safe wrappers of the gocv core functions
+// =============================================================================
+
+CvStatus Mat_NewWithSize_Checked(int rows, int cols, int type, Mat* result);
+CvStatus Mat_NewFromScalar_Checked(Scalar ar, int type, Mat* result);
+CvStatus Mat_NewFromBytes_Checked(int rows, int cols, int type, ByteArray buf, Mat* result);
+CvStatus Mat_CopyTo_Checked(Mat m, Mat dst);
+CvStatus Mat_CopyToWithMask_Checked(Mat m, Mat dst, Mat mask);
+CvStatus Mat_ConvertTo_Checked(Mat m, Mat dst, int type);
+CvStatus Mat_Region_Checked(Mat m, Rect r, Mat* result);
+CvStatus Mat_Reshape_Checked(Mat m, int cn, int rows, Mat* result);
+CvStatus Mat_ConvertFp16_Checked(Mat m, Mat* result);
+CvStatus Mat_Mean_Checked(Mat m, Scalar* result);
+CvStatus Mat_Sum_Checked(Mat m, Scalar* result);
+CvStatus LUT_Checked(Mat src, Mat lut, Mat dst);
+CvStatus Mat_AbsDiff_Checked(Mat src1, Mat src2, Mat dst);
+CvStatus Mat_Add_Checked(Mat src1, Mat src2, Mat dst, Mat mask, int dtype);
+CvStatus Mat_AddWeighted_Checked(Mat src1, double alpha, Mat src2, double beta, double gamma, Mat dst);
+CvStatus Mat_BitwiseAnd_Checked(Mat src1, Mat src2, Mat dst);
+CvStatus Mat_BitwiseNot_Checked(Mat src1, Mat dst);
+CvStatus Mat_BitwiseOr_Checked(Mat src1, Mat src2, Mat dst);
+CvStatus Mat_BitwiseXor_Checked(Mat src1, Mat src2, Mat dst);
+CvStatus Mat_BatchDistance_Checked(Mat src1,
+                                   Mat src2,
+                                   Mat dist,
+                                   int dtype,
+                                   Mat nidx,
+                                   int normType,
+                                   int K,
+                                   Mat mask,
+                                   int update,
+                                   bool crosscheck);
+CvStatus Mat_BorderInterpolate_Checked(int p, int len, int borderType, int* result);
+CvStatus Mat_CalcCovarMatrix_Checked(Mat samples, Mat covar, Mat mean, int flags, int ctype);
+CvStatus Mat_CartToPolar_Checked(Mat x, Mat y, Mat magnitude, Mat angle, bool angleInDegrees);
+CvStatus Mat_Compare_Checked(Mat src1, Mat src2, Mat dst, int ct);
+CvStatus Mat_CountNonZero_Checked(Mat src, int* result);
+CvStatus Mat_CompleteSymm_Checked(Mat m, bool lowerToUpper);
+CvStatus Mat_ConvertScaleAbs_Checked(Mat src, Mat dst, double alpha, double beta);
+CvStatus Mat_CopyMakeBorder_Checked(
+    Mat src, Mat dst, int top, int bottom, int left, int right, int borderType, Scalar value);
+CvStatus Mat_MinMaxLoc_Checked(Mat m, double* minVal, double* maxVal, Point* minLoc, Point* maxLoc);
+
+// =============================================================================
+//   Arithmetic operators
+// =============================================================================
+
+CvStatus Mat_AddScalar_Checked(Mat src, Scalar value, Mat dst);
+CvStatus Mat_SubtractScalar_Checked(Mat src, Scalar value, Mat dst);
+CvStatus Mat_SubtractFromScalar_Checked(Scalar value, Mat src, Mat dst);
+CvStatus Mat_MultiplyScalar_Checked(Mat src, Scalar value, Mat dst);
+CvStatus Mat_DivideScalar_Checked(Mat src, Scalar value, Mat dst);
+CvStatus Mat_Scale_Checked(Mat src, double alpha, Mat dst);
+CvStatus Mat_BitwiseAndScalar_Checked(Mat src, Scalar value, Mat dst);
+CvStatus Mat_BitwiseOrScalar_Checked(Mat src, Scalar value, Mat dst);
+CvStatus Mat_BitwiseXorScalar_Checked(Mat src, Scalar value, Mat dst);
+
+// =============================================================================
+//   Per-element arithmetic and math functions
+// =============================================================================
+
+// Optional Mat arguments (e.g. masks) may be NULL, and a dtype of -1 keeps
+// the depth of the input.
+CvStatus Mat_Subtract_Checked(Mat src1, Mat src2, Mat dst, Mat mask, int dtype);
+CvStatus Mat_Multiply_Checked(Mat src1, Mat src2, Mat dst, double scale, int dtype);
+CvStatus Mat_Divide_Checked(Mat src1, Mat src2, Mat dst, double scale, int dtype);
+CvStatus Mat_ScaleAdd_Checked(Mat src1, double alpha, Mat src2, Mat dst);
+CvStatus Mat_Pow_Checked(Mat src, double power, Mat dst);
+CvStatus Mat_Sqrt_Checked(Mat src, Mat dst);
+CvStatus Mat_Exp_Checked(Mat src, Mat dst);
+CvStatus Mat_Log_Checked(Mat src, Mat dst);
+CvStatus Mat_Magnitude_Checked(Mat x, Mat y, Mat magnitude);
+CvStatus Mat_Phase_Checked(Mat x, Mat y, Mat angle, bool angleInDegrees);
+CvStatus Mat_PolarToCart_Checked(Mat magnitude, Mat angle, Mat x, Mat y, bool angleInDegrees);
+CvStatus Mat_Min_Checked(Mat src1, Mat src2, Mat dst);
+CvStatus Mat_Max_Checked(Mat src1, Mat src2, Mat dst);
+CvStatus Mat_Abs_Checked(Mat src, Mat dst);
+
+// =============================================================================
+//   Discrete Fourier and cosine transforms
+// =============================================================================
+
+CvStatus Mat_DFT_Checked(Mat src, Mat dst, int flags, int nonzeroRows);
+CvStatus Mat_IDFT_Checked(Mat src, Mat dst, int flags, int nonzeroRows);
+CvStatus Mat_DCT_Checked(Mat src, Mat dst, int flags);
+CvStatus Mat_IDCT_Checked(Mat src, Mat dst, int flags);
+CvStatus Mat_MulSpectrums_Checked(Mat a, Mat b, Mat dst, int flags, bool conjB);
+int GetOptimalDFTSize(int vecsize);
+
+// =============================================================================
+//   Channels
+// =============================================================================
+
+// The Mats of the result must have been created by the caller, one per channel.
+CvStatus Mat_Split_Checked(Mat src, MatArray result);
+CvStatus Mat_Merge_Checked(MatArray mats, Mat dst);
+CvStatus Mat_MixChannels_Checked(MatArray src, MatArray dst, const int* fromTo, int npairs);
+CvStatus Mat_ExtractChannel_Checked(Mat src, Mat dst, int coi);
+CvStatus Mat_InsertChannel_Checked(Mat src, Mat dst, int coi);
+
+// =============================================================================
+//   Geometric array operations
+// =============================================================================
+
+CvStatus Mat_Flip_Checked(Mat src, Mat dst, int flipCode);
+CvStatus Mat_Rotate_Checked(Mat src, Mat dst, int rotateCode);
+CvStatus Mat_Repeat_Checked(Mat src, int ny, int nx, Mat dst);
+CvStatus Mat_Hconcat_Checked(MatArray mats, Mat dst);
+CvStatus Mat_Vconcat_Checked(MatArray mats, Mat dst);
+
+// =============================================================================
+//   Statistics
+// =============================================================================
+
+CvStatus Mat_MeanWithMask_Checked(Mat m, Mat mask, Scalar* result);
+CvStatus Mat_MeanStdDev_Checked(Mat src, Mat mask, Scalar* mean, Scalar* stddev);
+CvStatus Mat_MinMaxLocWithMask_Checked(Mat m, Mat mask, double* minVal, double* maxVal, Point* minLoc, Point* maxLoc);
+// minIdx and maxIdx may be NULL, otherwise they hold at least max(dims, 2) elements.
+CvStatus Mat_MinMaxIdx_Checked(Mat src, Mat mask, double* minVal, double* maxVal, int* minIdx, int* maxIdx);
+CvStatus Mat_Norm_Checked(Mat src, int normType, Mat mask, double* result);
+CvStatus Mat_NormDiff_Checked(Mat src1, Mat src2, int normType, Mat mask, double* result);
+CvStatus Mat_InRange_Checked(Mat src, Scalar lower, Scalar upper, Mat dst);
+CvStatus Mat_Normalize_Checked(Mat src, Mat dst, double alpha, double beta, int normType, int dtype, Mat mask);
+CvStatus Mat_Reduce_Checked(Mat src, Mat dst, int dim, int rtype, int dtype);
+
+// =============================================================================
+//   Linear algebra
+// =============================================================================
+
+CvStatus Mat_Gemm_Checked(Mat src1, Mat src2, double alpha, Mat src3, double beta, Mat dst, int flags);
+CvStatus Mat_Transpose_Checked(Mat src, Mat dst);
+CvStatus Mat_Invert_Checked(Mat src, Mat dst, int flags, double* result);
+CvStatus Mat_Solve_Checked(Mat src1, Mat src2, Mat dst, int flags, bool* result);
+CvStatus Mat_Determinant_Checked(Mat m, double* result);
+CvStatus Mat_Trace_Checked(Mat m, Scalar* result);
+CvStatus SVD_Compute_Checked(Mat src, Mat w, Mat u, Mat vt, int flags);
+CvStatus SVD_BackSubst_Checked(Mat w, Mat u, Mat vt, Mat rhs, Mat dst);
+CvStatus Mat_Eigen_Checked(Mat src, Mat eigenvalues, Mat eigenvectors, bool* result);
+CvStatus Mat_MulTransposed_Checked(Mat src, Mat dst, bool aTa, Mat delta, double scale, int dtype);
+CvStatus Mat_Cross_Checked(Mat a, Mat b, Mat dst);
+CvStatus Mat_Dot_Checked(Mat a, Mat b, double* result);
+
+// =============================================================================
+//   PCA
+// =============================================================================
+
+// A NULL mean is computed from the data.
+CvStatus PCA_New(Mat data, Mat mean, int flags, int maxComponents, PCA* result);
+CvStatus PCA_NewWithVariance(Mat data, Mat mean, int flags, double retainedVariance, PCA* result);
+void PCA_Close(PCA pca);
+CvStatus PCA_Project(PCA pca, Mat vec, Mat result);
+CvStatus PCA_BackProject(PCA pca, Mat vec, Mat result);
+Mat PCA_GetMean(PCA pca);
+Mat PCA_GetEigenvalues(PCA pca);
+Mat PCA_GetEigenvectors(PCA pca);
+
+// =============================================================================
+//   Random numbers
+// =============================================================================
+
+RNG RNG_New(uint64_t state);
+void RNG_Close(RNG rng);
+unsigned int RNG_Next(RNG rng);
+int RNG_UniformInt(RNG rng, int a, int b);
+double RNG_UniformDouble(RNG rng, double a, double b);
+double RNG_Gaussian(RNG rng, double sigma);
+CvStatus RNG_Fill(RNG rng, Mat mat, int distType, Scalar a, Scalar b);
+CvStatus Mat_RandU_Checked(Mat dst, Scalar low, Scalar high);
+CvStatus Mat_RandN_Checked(Mat dst, Scalar mean, Scalar stddev);
+// A NULL rng uses the default random number generator of the thread.
+CvStatus Mat_RandShuffle_Checked(Mat dst, double iterFactor, RNG rng);
+void SetRNGSeed(int seed);
+
+// =============================================================================
+//   Clustering
+// =============================================================================
+
+// bestLabels holds the initial labels if flags contains KMEANS_USE_INITIAL_LABELS.
+CvStatus Mat_KMeans_Checked(Mat data, int k, Mat bestLabels, TermCriteria criteria, int attempts, int flags,
+                            Mat centers, double* compactness);
+
+#ifdef __cplusplus
+}
+
+CvStatus CvStatus_Ok();
+CvStatus CvStatus_FromException(const cv::Exception& e);
+CvStatus CvStatus_FromMessage(int code, const char* err);
+
+// Runs the statements between CV_TRY and CV_CATCH and turns any exception they
+// raise into the CvStatus returned by the enclosing function.
+#define CV_TRY try {
+#define CV_CATCH                                                                   \
+    }                                                                              \
+    catch (const cv::Exception& e) {                                               \
+        return CvStatus_FromException(e);                                          \
+    }                                                                              \
+    catch (const std::exception& e) {                                              \
+        return CvStatus_FromMessage(cv::Error::StsError, e.what());                \
+    }                                                                              \
+    catch (...) {                                                                  \
+        return CvStatus_FromMessage(cv::Error::StsError, "unknown C++ exception"); \
+    }                                                                              \
+    return CvStatus_Ok();
+
+// Passes an optional Mat argument on to OpenCV, NULL meaning cv::noArray().
+inline cv::_InputArray ToInputArray(Mat m) {
+    return m ? cv::_InputArray(*m) : cv::_InputArray();
+}
+
+inline cv::TermCriteria ToCvTermCriteria(TermCriteria criteria) {
+    return cv::TermCriteria(criteria.type, criteria.maxCount, criteria.epsilon);
+}
+#endif
+
+#endif  // _OPENCV3_CORE_EXT_H_
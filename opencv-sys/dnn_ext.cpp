@@ -0,0 +1,49 @@
+#include "dnn_ext.h"
+
+CvStatus Net_ReadNetFromCaffe_Checked(const char* prototxt, const char* caffeModel, Net* result) {
+    CV_TRY
+    *result = Net_ReadNetFromCaffe(prototxt, caffeModel);
+    CV_CATCH
+}
+
+CvStatus Net_ReadNetFromTensorflow_Checked(const char* model, Net* result) {
+    CV_TRY
+    *result = Net_ReadNetFromTensorflow(model);
+    CV_CATCH
+}
+
+CvStatus Net_SetInput_Checked(Net net, Mat blob, const char* name) {
+    CV_TRY
+    Net_SetInput(net, blob, name);
+    CV_CATCH
+}
+
+CvStatus Net_Forward_Checked(Net net, const char* outputName, Mat* result) {
+    CV_TRY
+    // The output refers to a blob of the network that the next forward pass
+    // overwrites, so it is copied.
+    *result = new cv::Mat(net->forward(outputName).clone());
+    CV_CATCH
+}
+
+CvStatus Net_BlobFromImage_Checked(
+    Mat image, double scalefactor, Size size, Scalar mean, bool swapRB, bool crop, Mat* result) {
+    CV_TRY
+    *result = Net_BlobFromImage(image, scalefactor, size, mean, swapRB, crop);
+    CV_CATCH
+}
+
+CvStatus Net_GetBlobChannel_Checked(Mat blob, int imgidx, int chnidx, Mat* result) {
+    CV_TRY
+    CV_Assert(blob->dims == 4 && blob->type() == CV_32F);
+    CV_Assert(0 <= imgidx && imgidx < blob->size[0] && 0 <= chnidx && chnidx < blob->size[1]);
+    // Copied, so that the channel does not alias the blob.
+    *result = new cv::Mat(cv::Mat(blob->size[2], blob->size[3], CV_32F, blob->ptr<float>(imgidx, chnidx)).clone());
+    CV_CATCH
+}
+
+CvStatus Net_GetBlobSize_Checked(Mat blob, Scalar* result) {
+    CV_TRY
+    *result = Net_GetBlobSize(blob);
+    CV_CATCH
+}
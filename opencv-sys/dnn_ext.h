@@ -0,0 +1,29 @@
+#ifndef _OPENCV3_DNN_EXT_H_
+#define _OPENCV3_DNN_EXT_H_
+
+#include <stdbool.h>
+#include "gocv/dnn.h"
+#include "core_ext.h"
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+// =============================================================================
+//   Exception-safe wrappers of the gocv dnn functions
+// =============================================================================
+
+CvStatus Net_ReadNetFromCaffe_Checked(const char* prototxt, const char* caffeModel, Net* result);
+CvStatus Net_ReadNetFromTensorflow_Checked(const char* model, Net* result);
+CvStatus Net_SetInput_Checked(Net net, Mat blob, const char* name);
+CvStatus Net_Forward_Checked(Net net, const char* outputName, Mat* result);
+CvStatus Net_BlobFromImage_Checked(
+    Mat image, double scalefactor, Size size, Scalar mean, bool swapRB, bool crop, Mat* result);
+CvStatus Net_GetBlobChannel_Checked(Mat blob, int imgidx, int chnidx, Mat* result);
+CvStatus Net_GetBlobSize_Checked(Mat blob, Scalar* result);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif  // _OPENCV3_DNN_EXT_H_
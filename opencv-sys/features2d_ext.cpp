@@ -0,0 +1,13 @@
+#include "features2d_ext.h"
+
+CvStatus MSER_Detect_Checked(MSER a, Mat src, KeyPoints* result) {
+    CV_TRY
+    *result = MSER_Detect(a, src);
+    CV_CATCH
+}
+
+CvStatus SimpleBlobDetector_Detect_Checked(SimpleBlobDetector b, Mat src, KeyPoints* result) {
+    CV_TRY
+    *result = SimpleBlobDetector_Detect(b, src);
+    CV_CATCH
+}
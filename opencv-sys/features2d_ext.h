@@ -0,0 +1,23 @@
+#ifndef _OPENCV3_FEATURES2D_EXT_H_
+#define _OPENCV3_FEATURES2D_EXT_H_
+
+#include <stdbool.h>
+#include "gocv/features2d.h"
+#include "core_ext.h"
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+// =============================================================================
+//   Exception-safe wrappers of the gocv features2d functions
+// =============================================================================
+
+CvStatus MSER_Detect_Checked(MSER a, Mat src, KeyPoints* result);
+CvStatus SimpleBlobDetector_Detect_Checked(SimpleBlobDetector b, Mat src, KeyPoints* result);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif  // _OPENCV3_FEATURES2D_EXT_H_
@@ -0,0 +1,55 @@
+#include "highgui_ext.h"
+
+CvStatus Window_New_Checked(const char* winname, int flags) {
+    CV_TRY
+    Window_New(winname, flags);
+    CV_CATCH
+}
+
+CvStatus Window_Close_Checked(const char* winname) {
+    CV_TRY
+    Window_Close(winname);
+    CV_CATCH
+}
+
+CvStatus Window_IMShow_Checked(const char* winname, Mat mat) {
+    CV_TRY
+    Window_IMShow(winname, mat);
+    CV_CATCH
+}
+
+CvStatus Window_WaitKey_Checked(int delay, int* result) {
+    CV_TRY
+    *result = Window_WaitKey(delay);
+    CV_CATCH
+}
+
+CvStatus Window_GetProperty_Checked(const char* winname, int flag, double* result) {
+    CV_TRY
+    *result = Window_GetProperty(winname, flag);
+    CV_CATCH
+}
+
+CvStatus Window_SetProperty_Checked(const char* winname, int flag, double value) {
+    CV_TRY
+    Window_SetProperty(winname, flag, value);
+    CV_CATCH
+}
+
+CvStatus Window_SetTitle_Checked(const char* winname, const char* title) {
+    CV_TRY
+    Window_SetTitle(winname, title);
+    CV_CATCH
+}
+
+CvStatus Window_Move_Checked(const char* winname, int x, int y) {
+    CV_TRY
+    Window_Move(winname, x, y);
+    CV_CATCH
+}
+
+CvStatus Window_Resize_Checked(const char* winname, int width, int height) {
+    CV_TRY
+    Window_Resize(winname, width, height);
+    CV_CATCH
+}
@@ -0,0 +1,30 @@
+#ifndef _OPENCV3_HIGHGUI_EXT_H_
+#define _OPENCV3_HIGHGUI_EXT_H_
+
+#include <stdbool.h>
+#include "gocv/highgui.h"
+#include "core_ext.h"
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+// =============================================================================
+//   Exception-safe wrappers of the gocv highgui functions
+// =============================================================================
+
+CvStatus Window_New_Checked(const char* winname, int flags);
+CvStatus Window_Close_Checked(const char* winname);
+CvStatus Window_IMShow_Checked(const char* winname, Mat mat);
+CvStatus Window_WaitKey_Checked(int delay, int* result);
+CvStatus Window_GetProperty_Checked(const char* winname, int flag, double* result);
+CvStatus Window_SetProperty_Checked(const char* winname, int flag, double value);
+CvStatus Window_SetTitle_Checked(const char* winname, const char* title);
+CvStatus Window_Move_Checked(const char* winname, int x, int y);
+CvStatus Window_Resize_Checked(const char* winname, int width, int height);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif  // _OPENCV3_HIGHGUI_EXT_H_
@@ -0,0 +1,19 @@
+#include "imgcodecs_ext.h"
+
+CvStatus Image_IMRead_Checked(const char* filename, int flags, Mat* result) {
+    CV_TRY
+    *result = Image_IMRead(filename, flags);
+    CV_CATCH
+}
+
+CvStatus Image_IMWrite_Checked(const char* filename, Mat img, bool* result) {
+    CV_TRY
+    *result = Image_IMWrite(filename, img);
+    CV_CATCH
+}
+
+CvStatus Image_IMWrite_WithParams_Checked(const char* filename, Mat img, IntVector params, bool* result) {
+    CV_TRY
+    *result = Image_IMWrite_WithParams(filename, img, params);
+    CV_CATCH
+}
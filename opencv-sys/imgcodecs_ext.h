@@ -0,0 +1,24 @@
+#ifndef _OPENCV3_IMGCODECS_EXT_H_
+#define _OPENCV3_IMGCODECS_EXT_H_
+
+#include <stdbool.h>
+#include "gocv/imgcodecs.h"
+#include "core_ext.h"
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+// =============================================================================
+//   Exception-safe wrappers of the gocv imgcodecs functions
+// =============================================================================
+
+CvStatus Image_IMRead_Checked(const char* filename, int flags, Mat* result);
+CvStatus Image_IMWrite_Checked(const char* filename, Mat img, bool* result);
+CvStatus Image_IMWrite_WithParams_Checked(const char* filename, Mat img, IntVector params, bool* result);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif  // _OPENCV3_IMGCODECS_EXT_H_
@@ -0,0 +1,104 @@
+#include "imgproc_ext.h"
+
+CvStatus ArcLength_Checked(Contour curve, bool isClosed, double* result) {
+    CV_TRY
+    *result = ArcLength(curve, isClosed);
+    CV_CATCH
+}
+
+CvStatus CvtColor_Checked(Mat src, Mat dst, int code) {
+    CV_TRY
+    CvtColor(src, dst, code);
+    CV_CATCH
+}
+
+CvStatus MatchTemplate_Checked(Mat image, Mat templ, Mat result, int method, Mat mask) {
+    CV_TRY
+    MatchTemplate(image, templ, result, method, mask);
+    CV_CATCH
+}
+
+CvStatus PyrDown_Checked(Mat src, Mat dst, Size dstsize, int borderType) {
+    CV_TRY
+    PyrDown(src, dst, dstsize, borderType);
+    CV_CATCH
+}
+
+CvStatus PyrUp_Checked(Mat src, Mat dst, Size dstsize, int borderType) {
+    CV_TRY
+    PyrUp(src, dst, dstsize, borderType);
+    CV_CATCH
+}
+
+CvStatus GaussianBlur_Checked(Mat src, Mat dst, Size ps, double sX, double sY, int bt) {
+    CV_TRY
+    GaussianBlur(src, dst, ps, sX, sY, bt);
+    CV_CATCH
+}
+
+CvStatus Laplacian_Checked(Mat src, Mat dst, int dDepth, int kSize, double scale, double delta, int borderType) {
+    CV_TRY
+    Laplacian(src, dst, dDepth, kSize, scale, delta, borderType);
+    CV_CATCH
+}
+
+CvStatus Scharr_Checked(Mat src, Mat dst, int dDepth, int dx, int dy, double scale, double delta, int borderType) {
+    CV_TRY
+    Scharr(src, dst, dDepth, dx, dy, scale, delta, borderType);
+    CV_CATCH
+}
+
+CvStatus MedianBlur_Checked(Mat src, Mat dst, int ksize) {
+    CV_TRY
+    MedianBlur(src, dst, ksize);
+    CV_CATCH
+}
+
+CvStatus Canny_Checked(Mat src, Mat edges, double t1, double t2) {
+    CV_TRY
+    Canny(src, edges, t1, t2);
+    CV_CATCH
+}
+
+CvStatus GoodFeaturesToTrack_Checked(Mat img, Mat corners, int maxCorners, double quality, double minDist) {
+    CV_TRY
+    GoodFeaturesToTrack(img, corners, maxCorners, quality, minDist);
+    CV_CATCH
+}
+
+CvStatus Threshold_Checked(Mat src, Mat dst, double thresh, double maxvalue, int typ) {
+    CV_TRY
+    Threshold(src, dst, thresh, maxvalue, typ);
+    CV_CATCH
+}
+
+CvStatus Circle_Checked(Mat img, Point center, int radius, Scalar color, int thickness) {
+    CV_TRY
+    Circle(img, center, radius, color, thickness);
+    CV_CATCH
+}
+
+CvStatus Ellipse_Checked(
+    Mat img, Point center, Point axes, double angle, double startAngle, double endAngle, Scalar color, int thickness) {
+    CV_TRY
+    Ellipse(img, center, axes, angle, startAngle, endAngle, color, thickness);
+    CV_CATCH
+}
+
+CvStatus Line_Checked(Mat img, Point pt1, Point pt2, Scalar color, int thickness) {
+    CV_TRY
+    Line(img, pt1, pt2, color, thickness);
+    CV_CATCH
+}
+
+CvStatus Rectangle_Checked(Mat img, Rect rect, Scalar color, int thickness) {
+    CV_TRY
+    Rectangle(img, rect, color, thickness);
+    CV_CATCH
+}
+
+CvStatus Resize_Checked(Mat src, Mat dst, Size sz, double fx, double fy, int interp) {
+    CV_TRY
+    Resize(src, dst, sz, fx, fy, interp);
+    CV_CATCH
+}
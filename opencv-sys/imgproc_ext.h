@@ -0,0 +1,39 @@
+#ifndef _OPENCV3_IMGPROC_EXT_H_
+#define _OPENCV3_IMGPROC_EXT_H_
+
+#include <stdbool.h>
+#include "gocv/imgproc.h"
+#include "core_ext.h"
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+// =============================================================================
+//   Exception-safe wrappers of the gocv imgproc functions
+// =============================================================================
+
+CvStatus ArcLength_Checked(Contour curve, bool isClosed, double* result);
+CvStatus CvtColor_Checked(Mat src, Mat dst, int code);
+CvStatus MatchTemplate_Checked(Mat image, Mat templ, Mat result, int method, Mat mask);
+CvStatus PyrDown_Checked(Mat src, Mat dst, Size dstsize, int borderType);
+CvStatus PyrUp_Checked(Mat src, Mat dst, Size dstsize, int borderType);
+CvStatus GaussianBlur_Checked(Mat src, Mat dst, Size ps, double sX, double sY, int bt);
+CvStatus Laplacian_Checked(Mat src, Mat dst, int dDepth, int kSize, double scale, double delta, int borderType);
+CvStatus Scharr_Checked(Mat src, Mat dst, int dDepth, int dx, int dy, double scale, double delta, int borderType);
+CvStatus MedianBlur_Checked(Mat src, Mat dst, int ksize);
+CvStatus Canny_Checked(Mat src, Mat edges, double t1, double t2);
+CvStatus GoodFeaturesToTrack_Checked(Mat img, Mat corners, int maxCorners, double quality, double minDist);
+CvStatus Threshold_Checked(Mat src, Mat dst, double thresh, double maxvalue, int typ);
+CvStatus Circle_Checked(Mat img, Point center, int radius, Scalar color, int thickness);
+CvStatus Ellipse_Checked(
+    Mat img, Point center, Point axes, double angle, double startAngle, double endAngle, Scalar color, int thickness);
+CvStatus Line_Checked(Mat img, Point pt1, Point pt2, Scalar color, int thickness);
+CvStatus Rectangle_Checked(Mat img, Rect rect, Scalar color, int thickness);
+CvStatus Resize_Checked(Mat src, Mat dst, Size sz, double fx, double fy, int interp);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif  // _OPENCV3_IMGPROC_EXT_H_
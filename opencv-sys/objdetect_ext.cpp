@@ -0,0 +1,20 @@
+#include "objdetect_ext.h"
+
+CvStatus CascadeClassifier_Load_Checked(CascadeClassifier cs, const char* name, int* result) {
+    CV_TRY
+    *result = CascadeClassifier_Load(cs, name);
+    CV_CATCH
+}
+
+CvStatus CascadeClassifier_DetectMultiScale_Checked(CascadeClassifier cs, Mat img, Rects* result) {
+    CV_TRY
+    *result = CascadeClassifier_DetectMultiScale(cs, img);
+    CV_CATCH
+}
+
+CvStatus CascadeClassifier_DetectMultiScaleWithParams_Checked(
+    CascadeClassifier cs, Mat img, double scale, int minNeighbors, int flags, Size minSize, Size maxSize, Rects* result) {
+    CV_TRY
+    *result = CascadeClassifier_DetectMultiScaleWithParams(cs, img, scale, minNeighbors, flags, minSize, maxSize);
+    CV_CATCH
+}
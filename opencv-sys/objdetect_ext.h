@@ -0,0 +1,25 @@
+#ifndef _OPENCV3_OBJDETECT_EXT_H_
+#define _OPENCV3_OBJDETECT_EXT_H_
+
+#include <stdbool.h>
+#include "gocv/objdetect.h"
+#include "core_ext.h"
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+// =============================================================================
+//   Exception-safe wrappers of the gocv objdetect functions
+// =============================================================================
+
+CvStatus CascadeClassifier_Load_Checked(CascadeClassifier cs, const char* name, int* result);
+CvStatus CascadeClassifier_DetectMultiScale_Checked(CascadeClassifier cs, Mat img, Rects* result);
+CvStatus CascadeClassifier_DetectMultiScaleWithParams_Checked(
+    CascadeClassifier cs, Mat img, double scale, int minNeighbors, int flags, Size minSize, Size maxSize, Rects* result);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif  // _OPENCV3_OBJDETECT_EXT_H_
@@ -0,0 +1,229 @@
+#include "video_ext.h"
+
+BackgroundSubtractorMOG2 BackgroundSubtractorMOG2_CreateWithParams(int history, double varThreshold, bool detectShadows) {
+    return new cv::Ptr<cv::BackgroundSubtractorMOG2>(
+        cv::createBackgroundSubtractorMOG2(history, varThreshold, detectShadows));
+}
+
+CvStatus BackgroundSubtractorMOG2_ApplyWithLearningRate(BackgroundSubtractorMOG2 b, Mat src, Mat dst, double learningRate) {
+    CV_TRY
+    (*b)->apply(*src, *dst, learningRate);
+    CV_CATCH
+}
+
+CvStatus BackgroundSubtractorMOG2_GetBackgroundImage(BackgroundSubtractorMOG2 b, Mat dst) {
+    CV_TRY
+    (*b)->getBackgroundImage(*dst);
+    CV_CATCH
+}
+
+BackgroundSubtractorKNN BackgroundSubtractorKNN_CreateWithParams(int history, double dist2Threshold, bool detectShadows) {
+    return new cv::Ptr<cv::BackgroundSubtractorKNN>(
+        cv::createBackgroundSubtractorKNN(history, dist2Threshold, detectShadows));
+}
+
+CvStatus BackgroundSubtractorKNN_ApplyWithLearningRate(BackgroundSubtractorKNN b, Mat src, Mat dst, double learningRate) {
+    CV_TRY
+    (*b)->apply(*src, *dst, learningRate);
+    CV_CATCH
+}
+
+CvStatus BackgroundSubtractorKNN_GetBackgroundImage(BackgroundSubtractorKNN b, Mat dst) {
+    CV_TRY
+    (*b)->getBackgroundImage(*dst);
+    CV_CATCH
+}
+
+CvStatus CalcOpticalFlowPyrLKWithParams(Mat prevImg,
+                                        Mat nextImg,
+                                        Points2f prevPts,
+                                        Points2f nextPts,
+                                        unsigned char* status,
+                                        float* err,
+                                        Size winSize,
+                                        int maxLevel,
+                                        TermCriteria criteria,
+                                        int flags,
+                                        double minEigThreshold) {
+    CV_TRY
+    std::vector<cv::Point2f> prev_pts;
+    std::vector<cv::Point2f> next_pts;
+    for (int i = 0; i < prevPts.length; ++i) {
+        prev_pts.push_back(cv::Point2f(prevPts.points[i].x, prevPts.points[i].y));
+        next_pts.push_back(cv::Point2f(nextPts.points[i].x, nextPts.points[i].y));
+    }
+
+    std::vector<uchar> vec_status;
+    std::vector<float> vec_err;
+    cv::Size cv_win_size(winSize.width, winSize.height);
+    cv::calcOpticalFlowPyrLK(*prevImg, *nextImg, prev_pts, next_pts, vec_status, vec_err, cv_win_size, maxLevel,
+                             ToCvTermCriteria(criteria), flags, minEigThreshold);
+
+    for (size_t i = 0; i < next_pts.size(); ++i) {
+        nextPts.points[i] = Point2f{next_pts[i].x, next_pts[i].y};
+        status[i] = vec_status[i];
+        err[i] = vec_err[i];
+    }
+    CV_CATCH
+}
+
+CvStatus CalcOpticalFlowFarneback_Checked(Mat prevImg,
+                                          Mat nextImg,
+                                          Mat flow,
+                                          double pyrScale,
+                                          int levels,
+                                          int winsize,
+                                          int iterations,
+                                          int polyN,
+                                          double polySigma,
+                                          int flags) {
+    CV_TRY
+    CalcOpticalFlowFarneback(prevImg, nextImg, flow, pyrScale, levels, winsize, iterations, polyN, polySigma, flags);
+    CV_CATCH
+}
+
+CvStatus MeanShift(Mat probImage, Rect* window, TermCriteria criteria, int* iterations) {
+    CV_TRY
+    cv::Rect cv_window(window->x, window->y, window->width, window->height);
+    *iterations = cv::meanShift(*probImage, cv_window, ToCvTermCriteria(criteria));
+    *window = Rect{cv_window.x, cv_window.y, cv_window.width, cv_window.height};
+    CV_CATCH
+}
+
+CvStatus CamShift(Mat probImage, Rect* window, TermCriteria criteria, RotatedRect2f* result) {
+    CV_TRY
+    cv::Rect cv_window(window->x, window->y, window->width, window->height);
+    cv::RotatedRect r = cv::CamShift(*probImage, cv_window, ToCvTermCriteria(criteria));
+    *window = Rect{cv_window.x, cv_window.y, cv_window.width, cv_window.height};
+    *result = RotatedRect2f{{r.center.x, r.center.y}, {r.size.width, r.size.height}, r.angle};
+    CV_CATCH
+}
+
+CvStatus KalmanFilter_New(int dynamParams, int measureParams, int controlParams, int type, KalmanFilter* result) {
+    CV_TRY
+    *result = new cv::KalmanFilter(dynamParams, measureParams, controlParams, type);
+    CV_CATCH
+}
+
+void KalmanFilter_Close(KalmanFilter kf) {
+    delete kf;
+    kf = nullptr;
+}
+
+CvStatus KalmanFilter_Predict(KalmanFilter kf, Mat* result) {
+    CV_TRY
+    *result = new cv::Mat(kf->predict().clone());
+    CV_CATCH
+}
+
+CvStatus KalmanFilter_PredictWithControl(KalmanFilter kf, Mat control, Mat* result) {
+    CV_TRY
+    *result = new cv::Mat(kf->predict(*control).clone());
+    CV_CATCH
+}
+
+CvStatus KalmanFilter_Correct(KalmanFilter kf, Mat measurement, Mat* result) {
+    CV_TRY
+    *result = new cv::Mat(kf->correct(*measurement).clone());
+    CV_CATCH
+}
+
+Mat KalmanFilter_GetStatePre(KalmanFilter kf) {
+    return new cv::Mat(kf->statePre);
+}
+
+Mat KalmanFilter_GetStatePost(KalmanFilter kf) {
+    return new cv::Mat(kf->statePost);
+}
+
+Mat KalmanFilter_GetTransitionMatrix(KalmanFilter kf) {
+    return new cv::Mat(kf->transitionMatrix);
+}
+
+Mat KalmanFilter_GetControlMatrix(KalmanFilter kf) {
+    return new cv::Mat(kf->controlMatrix);
+}
+
+Mat KalmanFilter_GetMeasurementMatrix(KalmanFilter kf) {
+    return new cv::Mat(kf->measurementMatrix);
+}
+
+Mat KalmanFilter_GetProcessNoiseCov(KalmanFilter kf) {
+    return new cv::Mat(kf->processNoiseCov);
+}
+
+Mat KalmanFilter_GetMeasurementNoiseCov(KalmanFilter kf) {
+    return new cv::Mat(kf->measurementNoiseCov);
+}
+
+Mat KalmanFilter_GetErrorCovPre(KalmanFilter kf) {
+    return new cv::Mat(kf->errorCovPre);
+}
+
+Mat KalmanFilter_GetGain(KalmanFilter kf) {
+    return new cv::Mat(kf->gain);
+}
+
+Mat KalmanFilter_GetErrorCovPost(KalmanFilter kf) {
+    return new cv::Mat(kf->errorCovPost);
+}
+
+CvStatus KalmanFilter_SetStatePre(KalmanFilter kf, Mat m) {
+    CV_TRY
+    m->copyTo(kf->statePre);
+    CV_CATCH
+}
+
+CvStatus KalmanFilter_SetStatePost(KalmanFilter kf, Mat m) {
+    CV_TRY
+    m->copyTo(kf->statePost);
+    CV_CATCH
+}
+
+CvStatus KalmanFilter_SetTransitionMatrix(KalmanFilter kf, Mat m) {
+    CV_TRY
+    m->copyTo(kf->transitionMatrix);
+    CV_CATCH
+}
+
+CvStatus KalmanFilter_SetControlMatrix(KalmanFilter kf, Mat m) {
+    CV_TRY
+    m->copyTo(kf->controlMatrix);
+    CV_CATCH
+}
+
+CvStatus KalmanFilter_SetMeasurementMatrix(KalmanFilter kf, Mat m) {
+    CV_TRY
+    m->copyTo(kf->measurementMatrix);
+    CV_CATCH
+}
+
+CvStatus KalmanFilter_SetProcessNoiseCov(KalmanFilter kf, Mat m) {
+    CV_TRY
+    m->copyTo(kf->processNoiseCov);
+    CV_CATCH
+}
+
+CvStatus KalmanFilter_SetMeasurementNoiseCov(KalmanFilter kf, Mat m) {
+    CV_TRY
+    m->copyTo(kf->measurementNoiseCov);
+    CV_CATCH
+}
+
+CvStatus KalmanFilter_SetErrorCovPre(KalmanFilter kf, Mat m) {
+    CV_TRY
+    m->copyTo(kf->errorCovPre);
+    CV_CATCH
+}
+
+CvStatus KalmanFilter_SetGain(KalmanFilter kf, Mat m) {
+    CV_TRY
+    m->copyTo(kf->gain);
+    CV_CATCH
+}
+
+CvStatus KalmanFilter_SetErrorCovPost(KalmanFilter kf, Mat m) {
+    CV_TRY
+    m->copyTo(kf->errorCovPost);
+    CV_CATCH
+}
@@ -0,0 +1,100 @@
+#ifndef _OPENCV3_VIDEO_EXT_H_
+#define _OPENCV3_VIDEO_EXT_H_
+
+#include <stdbool.h>
+#include "gocv/video.h"
+#include "core_ext.h"
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+// =============================================================================
+//   BackgroundSubtractor
+// =============================================================================
+
+BackgroundSubtractorMOG2 BackgroundSubtractorMOG2_CreateWithParams(int history, double varThreshold, bool detectShadows);
+CvStatus BackgroundSubtractorMOG2_ApplyWithLearningRate(BackgroundSubtractorMOG2 b, Mat src, Mat dst, double learningRate);
+CvStatus BackgroundSubtractorMOG2_GetBackgroundImage(BackgroundSubtractorMOG2 b, Mat dst);
+
+BackgroundSubtractorKNN BackgroundSubtractorKNN_CreateWithParams(int history, double dist2Threshold, bool detectShadows);
+CvStatus BackgroundSubtractorKNN_ApplyWithLearningRate(BackgroundSubtractorKNN b, Mat src, Mat dst, double learningRate);
+CvStatus BackgroundSubtractorKNN_GetBackgroundImage(BackgroundSubtractorKNN b, Mat dst);
+
+// =============================================================================
+//   Optical flow
+// =============================================================================
+
+CvStatus CalcOpticalFlowPyrLKWithParams(Mat prevImg,
+                                        Mat nextImg,
+                                        Points2f prevPts,
+                                        Points2f nextPts,
+                                        unsigned char* status,
+                                        float* err,
+                                        Size winSize,
+                                        int maxLevel,
+                                        TermCriteria criteria,
+                                        int flags,
+                                        double minEigThreshold);
+
+CvStatus CalcOpticalFlowFarneback_Checked(Mat prevImg,
+                                          Mat nextImg,
+                                          Mat flow,
+                                          double pyrScale,
+                                          int levels,
+                                          int winsize,
+                                          int iterations,
+                                          int polyN,
+                                          double polySigma,
+                                          int flags);
+
+// =============================================================================
+//   Object tracking
+// =============================================================================
+
+CvStatus MeanShift(Mat probImage, Rect* window, TermCriteria criteria, int* iterations);
+CvStatus CamShift(Mat probImage, Rect* window, TermCriteria criteria, RotatedRect2f* result);
+
+// =============================================================================
+//   KalmanFilter
+// =============================================================================
+
+#ifdef __cplusplus
+typedef cv::KalmanFilter* KalmanFilter;
+#else
+typedef void* KalmanFilter;
+#endif
+
+CvStatus KalmanFilter_New(int dynamParams, int measureParams, int controlParams, int type, KalmanFilter* result);
+void KalmanFilter_Close(KalmanFilter kf);
+CvStatus KalmanFilter_Predict(KalmanFilter kf, Mat* result);
+CvStatus KalmanFilter_PredictWithControl(KalmanFilter kf, Mat control, Mat* result);
+CvStatus KalmanFilter_Correct(KalmanFilter kf, Mat measurement, Mat* result);
+
+Mat KalmanFilter_GetStatePre(KalmanFilter kf);
+Mat KalmanFilter_GetStatePost(KalmanFilter kf);
+Mat KalmanFilter_GetTransitionMatrix(KalmanFilter kf);
+Mat KalmanFilter_GetControlMatrix(KalmanFilter kf);
+Mat KalmanFilter_GetMeasurementMatrix(KalmanFilter kf);
+Mat KalmanFilter_GetProcessNoiseCov(KalmanFilter kf);
+Mat KalmanFilter_GetMeasurementNoiseCov(KalmanFilter kf);
+Mat KalmanFilter_GetErrorCovPre(KalmanFilter kf);
+Mat KalmanFilter_GetGain(KalmanFilter kf);
+Mat KalmanFilter_GetErrorCovPost(KalmanFilter kf);
+
+CvStatus KalmanFilter_SetStatePre(KalmanFilter kf, Mat m);
+CvStatus KalmanFilter_SetStatePost(KalmanFilter kf, Mat m);
+CvStatus KalmanFilter_SetTransitionMatrix(KalmanFilter kf, Mat m);
+CvStatus KalmanFilter_SetControlMatrix(KalmanFilter kf, Mat m);
+CvStatus KalmanFilter_SetMeasurementMatrix(KalmanFilter kf, Mat m);
+CvStatus KalmanFilter_SetProcessNoiseCov(KalmanFilter kf, Mat m);
+CvStatus KalmanFilter_SetMeasurementNoiseCov(KalmanFilter kf, Mat m);
+CvStatus KalmanFilter_SetErrorCovPre(KalmanFilter kf, Mat m);
+CvStatus KalmanFilter_SetGain(KalmanFilter kf, Mat m);
+CvStatus KalmanFilter_SetErrorCovPost(KalmanFilter kf, Mat m);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif  // _OPENCV3_VIDEO_EXT_H_
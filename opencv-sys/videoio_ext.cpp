@@ -0,0 +1,45 @@
+#include "videoio_ext.h"
+
+CvStatus VideoCapture_Open_Checked(VideoCapture v, const char* uri, bool* result) {
+    CV_TRY
+    *result = VideoCapture_Open(v, uri);
+    CV_CATCH
+}
+
+CvStatus VideoCapture_OpenDevice_Checked(VideoCapture v, int device, bool* result) {
+    CV_TRY
+    *result = VideoCapture_OpenDevice(v, device);
+    CV_CATCH
+}
+
+CvStatus VideoCapture_Set_Checked(VideoCapture v, int prop, double param) {
+    CV_TRY
+    VideoCapture_Set(v, prop, param);
+    CV_CATCH
+}
+
+CvStatus VideoCapture_Read_Checked(VideoCapture v, Mat buf, bool* result) {
+    CV_TRY
+    *result = v->read(*buf);
+    CV_CATCH
+}
+
+CvStatus VideoCapture_Grab_Checked(VideoCapture v, int skip) {
+    CV_TRY
+    VideoCapture_Grab(v, skip);
+    CV_CATCH
+}
+
+CvStatus VideoWriter_OpenWithFourCC(
+    VideoWriter vw, const char* name, int fourcc, double fps, Size size, bool isColor, bool* result) {
+    CV_TRY
+    cv::Size cv_size(size.width, size.height);
+    *result = vw->open(name, fourcc, fps, cv_size, isColor);
+    CV_CATCH
+}
+
+CvStatus VideoWriter_Write_Checked(VideoWriter vw, Mat img) {
+    CV_TRY
+    *vw << *img;
+    CV_CATCH
+}
@@ -0,0 +1,34 @@
+#ifndef _OPENCV3_VIDEOIO_EXT_H_
+#define _OPENCV3_VIDEOIO_EXT_H_
+
+#include <stdbool.h>
+#include "gocv/videoio.h"
+#include "core_ext.h"
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+// =============================================================================
+//   VideoCapture
+// =============================================================================
+
+CvStatus VideoCapture_Open_Checked(VideoCapture v, const char* uri, bool* result);
+CvStatus VideoCapture_OpenDevice_Checked(VideoCapture v, int device, bool* result);
+CvStatus VideoCapture_Set_Checked(VideoCapture v, int prop, double param);
+CvStatus VideoCapture_Read_Checked(VideoCapture v, Mat buf, bool* result);
+CvStatus VideoCapture_Grab_Checked(VideoCapture v, int skip);
+
+// =============================================================================
+//   VideoWriter
+// =============================================================================
+
+CvStatus VideoWriter_OpenWithFourCC(
+    VideoWriter vw, const char* name, int fourcc, double fps, Size size, bool isColor, bool* result);
+CvStatus VideoWriter_Write_Checked(VideoWriter vw, Mat img);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif  // _OPENCV3_VIDEOIO_EXT_H_
@@ -9,4 +9,14 @@
 #include "gocv/video.h"
 #include "gocv/videoio.h"
 
+#include "core_ext.h"
+#include "dnn_ext.h"
+#include "features2d_ext.h"
+#include "highgui_ext.h"
+#include "imgcodecs_ext.h"
+#include "imgproc_ext.h"
+#include "objdetect_ext.h"
+#include "video_ext.h"
+#include "videoio_ext.h"
+
 #include "cuda.h"
@@ -2,7 +2,15 @@
 //!
 //! [opencv-core]: https://docs.opencv.org/master/d0/de1/group__core.html
 use opencv_sys as ffi;
-use num_traits::FromPrimitive;
+use std::marker::PhantomData;
+use std::ops::Deref;
+use std::ptr;
+use std::slice;
+use {CvError, Error};
+
+pub mod linalg;
+
+pub use self::linalg::transpose;
 
 /// The class `Mat` represents an n-dimensional dense numerical single-channel or multi-channel array.
 /// It can be used to store real or complex-valued vectors and matrices, grayscale or color images,
@@ -30,9 +38,72 @@ pub use opencv_sys::Scalar;
 pub use opencv_sys::Rect;
 pub use opencv_sys::Size;
 pub use opencv_sys::Point;
+pub use opencv_sys::Point2f;
+pub use opencv_sys::Size2f;
+pub use opencv_sys::RotatedRect2f as RotatedRect;
 pub use opencv_sys::KeyPoint;
 
-/// Here is the `CvType` in an easy-to-read table.
+#[cfg(feature = "image")]
+pub use image_interop::{ChannelOrder, ImagePixel};
+
+/// Depth of the elements of a `Mat`, i.e. the type of a single channel.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, FromPrimitive, ToPrimitive)]
+pub enum Depth {
+    /// 8 bit unsigned (`CV_8U`)
+    Cv8U = 0,
+    /// 8 bit signed (`CV_8S`)
+    Cv8S = 1,
+    /// 16 bit unsigned (`CV_16U`)
+    Cv16U = 2,
+    /// 16 bit signed (`CV_16S`)
+    Cv16S = 3,
+    /// 32 bit signed (`CV_32S`)
+    Cv32S = 4,
+    /// 32 bit float (`CV_32F`)
+    Cv32F = 5,
+    /// 64 bit float (`CV_64F`)
+    Cv64F = 6,
+    /// 16 bit float (`CV_16F`). Only OpenCV 3.4 and later know about this
+    /// depth, older versions reserve the value for user types.
+    Cv16F = 7,
+}
+
+const DEPTH_MASK: i32 = 7;
+const CN_SHIFT: i32 = 3;
+const TYPE_MASK: i32 = 0xfff;
+
+impl Depth {
+    /// Returns the size of a single channel element in bytes.
+    pub fn size(self) -> usize {
+        match self {
+            Depth::Cv8U | Depth::Cv8S => 1,
+            Depth::Cv16U | Depth::Cv16S | Depth::Cv16F => 2,
+            Depth::Cv32S | Depth::Cv32F => 4,
+            Depth::Cv64F => 8,
+        }
+    }
+
+    fn from_bits(bits: i32) -> Depth {
+        match bits & DEPTH_MASK {
+            0 => Depth::Cv8U,
+            1 => Depth::Cv8S,
+            2 => Depth::Cv16U,
+            3 => Depth::Cv16S,
+            4 => Depth::Cv32S,
+            5 => Depth::Cv32F,
+            6 => Depth::Cv64F,
+            _ => Depth::Cv16F,
+        }
+    }
+}
+
+/// The type of the elements of a `Mat`: a [Depth](enum.Depth.html) together
+/// with a number of channels between 1 and `CvType::CN_MAX`.
+///
+/// The common combinations are available as constants such as
+/// `CvType::Cv8UC3`. The value OpenCV uses for a type is `depth + (channels -
+/// 1) * 8`, here is the table:
 ///
 /// |        | C1 | C2 | C3 | C4 | C(5) | C(6) | C(7) | C(8) |
 /// |--------|----|----|----|----|------|------|------|------|
@@ -43,39 +114,342 @@ pub use opencv_sys::KeyPoint;
 /// | CV_32S |  4 | 12 | 20 | 28 |   36 |   44 |   52 |   60 |
 /// | CV_32F |  5 | 13 | 21 | 29 |   37 |   45 |   53 |   61 |
 /// | CV_64F |  6 | 14 | 22 | 30 |   38 |   46 |   54 |   62 |
-#[repr(C)]
-#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, FromPrimitive, ToPrimitive)]
-pub enum CvType {
-    /// 8 bit unsigned, single channel (grey image)
-    Cv8UC1 = 0,
-    /// 8 bit signed, single channel (grey image)
-    Cv8SC1 = 1,
-    /// 16 bit unsigned, single channel (grey image)
-    Cv16UC1 = 2,
-    /// 16 bit signed, single channel (grey image)
-    Cv16SC1 = 3,
-    /// 32 bit signed, single channel (grey image)
-    Cv32SC1 = 4,
-    /// 32 bit float, single channel (grey image)
-    Cv32FC1 = 5,
-    /// 32 bit float, single channel (grey image)
-    Cv64FC1 = 6,
-    /// 8 bit, two channel (rarelly seen)
-    Cv8UC2 = 8,
-    /// 8 bit unsigned, three channels (RGB image)
-    Cv8UC3 = 16,
-    /// 8 bit signed, three channels (RGB image)
-    Cv8SC3 = 17,
-    /// 16 bit unsigned, three channels (RGB image)
-    Cv16UC3 = 18,
-    /// 16 bit signed, three channels (RGB image)
-    Cv16SC3 = 19,
-    /// 32 bit signed, three channels (RGB image)
-    Cv32SC3 = 20,
-    /// 32 bit float, three channels (RGB image)
-    Cv32FC3 = 21,
-    /// 32 bit float, three channels (RGB image)
-    Cv64FC3 = 22,
+/// | CV_16F |  7 | 15 | 23 | 31 |   39 |   47 |   55 |   63 |
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct CvType {
+    depth: Depth,
+    channels: i32,
+}
+
+#[allow(non_upper_case_globals)]
+impl CvType {
+    /// Maximum number of channels of a `Mat`.
+    pub const CN_MAX: i32 = 512;
+
+    /// 8 bit unsigned, single channel
+    pub const Cv8UC1: CvType = CvType {
+        depth: Depth::Cv8U,
+        channels: 1,
+    };
+    /// 8 bit unsigned, two channels
+    pub const Cv8UC2: CvType = CvType {
+        depth: Depth::Cv8U,
+        channels: 2,
+    };
+    /// 8 bit unsigned, three channels
+    pub const Cv8UC3: CvType = CvType {
+        depth: Depth::Cv8U,
+        channels: 3,
+    };
+    /// 8 bit unsigned, four channels
+    pub const Cv8UC4: CvType = CvType {
+        depth: Depth::Cv8U,
+        channels: 4,
+    };
+    /// 8 bit signed, single channel
+    pub const Cv8SC1: CvType = CvType {
+        depth: Depth::Cv8S,
+        channels: 1,
+    };
+    /// 8 bit signed, two channels
+    pub const Cv8SC2: CvType = CvType {
+        depth: Depth::Cv8S,
+        channels: 2,
+    };
+    /// 8 bit signed, three channels
+    pub const Cv8SC3: CvType = CvType {
+        depth: Depth::Cv8S,
+        channels: 3,
+    };
+    /// 8 bit signed, four channels
+    pub const Cv8SC4: CvType = CvType {
+        depth: Depth::Cv8S,
+        channels: 4,
+    };
+    /// 16 bit unsigned, single channel
+    pub const Cv16UC1: CvType = CvType {
+        depth: Depth::Cv16U,
+        channels: 1,
+    };
+    /// 16 bit unsigned, two channels
+    pub const Cv16UC2: CvType = CvType {
+        depth: Depth::Cv16U,
+        channels: 2,
+    };
+    /// 16 bit unsigned, three channels
+    pub const Cv16UC3: CvType = CvType {
+        depth: Depth::Cv16U,
+        channels: 3,
+    };
+    /// 16 bit unsigned, four channels
+    pub const Cv16UC4: CvType = CvType {
+        depth: Depth::Cv16U,
+        channels: 4,
+    };
+    /// 16 bit signed, single channel
+    pub const Cv16SC1: CvType = CvType {
+        depth: Depth::Cv16S,
+        channels: 1,
+    };
+    /// 16 bit signed, two channels
+    pub const Cv16SC2: CvType = CvType {
+        depth: Depth::Cv16S,
+        channels: 2,
+    };
+    /// 16 bit signed, three channels
+    pub const Cv16SC3: CvType = CvType {
+        depth: Depth::Cv16S,
+        channels: 3,
+    };
+    /// 16 bit signed, four channels
+    pub const Cv16SC4: CvType = CvType {
+        depth: Depth::Cv16S,
+        channels: 4,
+    };
+    /// 32 bit signed, single channel
+    pub const Cv32SC1: CvType = CvType {
+        depth: Depth::Cv32S,
+        channels: 1,
+    };
+    /// 32 bit signed, two channels
+    pub const Cv32SC2: CvType = CvType {
+        depth: Depth::Cv32S,
+        channels: 2,
+    };
+    /// 32 bit signed, three channels
+    pub const Cv32SC3: CvType = CvType {
+        depth: Depth::Cv32S,
+        channels: 3,
+    };
+    /// 32 bit signed, four channels
+    pub const Cv32SC4: CvType = CvType {
+        depth: Depth::Cv32S,
+        channels: 4,
+    };
+    /// 32 bit float, single channel
+    pub const Cv32FC1: CvType = CvType {
+        depth: Depth::Cv32F,
+        channels: 1,
+    };
+    /// 32 bit float, two channels
+    pub const Cv32FC2: CvType = CvType {
+        depth: Depth::Cv32F,
+        channels: 2,
+    };
+    /// 32 bit float, three channels
+    pub const Cv32FC3: CvType = CvType {
+        depth: Depth::Cv32F,
+        channels: 3,
+    };
+    /// 32 bit float, four channels
+    pub const Cv32FC4: CvType = CvType {
+        depth: Depth::Cv32F,
+        channels: 4,
+    };
+    /// 64 bit float, single channel
+    pub const Cv64FC1: CvType = CvType {
+        depth: Depth::Cv64F,
+        channels: 1,
+    };
+    /// 64 bit float, two channels
+    pub const Cv64FC2: CvType = CvType {
+        depth: Depth::Cv64F,
+        channels: 2,
+    };
+    /// 64 bit float, three channels
+    pub const Cv64FC3: CvType = CvType {
+        depth: Depth::Cv64F,
+        channels: 3,
+    };
+    /// 64 bit float, four channels
+    pub const Cv64FC4: CvType = CvType {
+        depth: Depth::Cv64F,
+        channels: 4,
+    };
+    /// 16 bit float, single channel
+    pub const Cv16FC1: CvType = CvType {
+        depth: Depth::Cv16F,
+        channels: 1,
+    };
+    /// 16 bit float, two channels
+    pub const Cv16FC2: CvType = CvType {
+        depth: Depth::Cv16F,
+        channels: 2,
+    };
+    /// 16 bit float, three channels
+    pub const Cv16FC3: CvType = CvType {
+        depth: Depth::Cv16F,
+        channels: 3,
+    };
+    /// 16 bit float, four channels
+    pub const Cv16FC4: CvType = CvType {
+        depth: Depth::Cv16F,
+        channels: 4,
+    };
+
+    /// Creates the type with the given depth and number of channels.
+    ///
+    /// Panics if `channels` is not between 1 and `CvType::CN_MAX`, so this is
+    /// meant for constant channel counts; use [try_new](#method.try_new) for
+    /// counts that come from elsewhere.
+    pub fn new(depth: Depth, channels: i32) -> CvType {
+        assert!(
+            (1..=CvType::CN_MAX).contains(&channels),
+            "invalid number of channels: {}",
+            channels
+        );
+        CvType { depth, channels }
+    }
+
+    /// Creates the type with the given depth and number of channels. Fails
+    /// with `CvError::InvalidChannels` if `channels` is not between 1 and
+    /// `CvType::CN_MAX`.
+    pub fn try_new(depth: Depth, channels: i32) -> Result<CvType, Error> {
+        if !(1..=CvType::CN_MAX).contains(&channels) {
+            return Err(CvError::InvalidChannels(channels).into());
+        }
+        Ok(CvType { depth, channels })
+    }
+
+    /// Decodes the value OpenCV uses for a type (e.g. the result of
+    /// `cv::Mat::type()`). Only the type bits are looked at, so this never
+    /// fails.
+    pub fn from_i32(t: i32) -> CvType {
+        let t = t & TYPE_MASK;
+        CvType {
+            depth: Depth::from_bits(t),
+            channels: (t >> CN_SHIFT) + 1,
+        }
+    }
+
+    /// Returns the value OpenCV uses for this type.
+    pub fn to_i32(self) -> i32 {
+        self.depth as i32 + ((self.channels - 1) << CN_SHIFT)
+    }
+
+    /// Returns the depth of this type.
+    pub fn depth(self) -> Depth {
+        self.depth
+    }
+
+    /// Returns the number of channels of this type.
+    pub fn channels(self) -> i32 {
+        self.channels
+    }
+
+    /// Returns the size of an element of this type in bytes, i.e. the size of
+    /// the depth times the number of channels.
+    pub fn elem_size(self) -> usize {
+        self.depth.size() * self.channels as usize
+    }
+}
+
+impl From<Depth> for CvType {
+    fn from(depth: Depth) -> CvType {
+        CvType { depth, channels: 1 }
+    }
+}
+
+/// The criteria for termination of iterative algorithms, shared by every
+/// binding that runs an iterative solver (optical flow, CamShift, ...).
+///
+/// An algorithm stops once the maximum number of iterations is reached, once
+/// the desired accuracy is reached, or at whichever of the two comes first.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TermCriteria {
+    max_count: Option<i32>,
+    epsilon: Option<f64>,
+}
+
+const TERM_CRITERIA_COUNT: i32 = 1;
+const TERM_CRITERIA_EPS: i32 = 2;
+
+impl TermCriteria {
+    /// Stops the algorithm after `max_count` iterations or once the desired
+    /// accuracy `epsilon` is reached, whichever comes first.
+    pub fn new(max_count: i32, epsilon: f64) -> Self {
+        TermCriteria {
+            max_count: Some(max_count),
+            epsilon: Some(epsilon),
+        }
+    }
+
+    /// Stops the algorithm after `max_count` iterations.
+    pub fn max_iter(max_count: i32) -> Self {
+        TermCriteria {
+            max_count: Some(max_count),
+            epsilon: None,
+        }
+    }
+
+    /// Stops the algorithm once the desired accuracy `epsilon` is reached.
+    pub fn eps(epsilon: f64) -> Self {
+        TermCriteria {
+            max_count: None,
+            epsilon: Some(epsilon),
+        }
+    }
+
+    /// Additionally stops the algorithm after `max_count` iterations.
+    pub fn with_max_iter(self, max_count: i32) -> Self {
+        TermCriteria {
+            max_count: Some(max_count),
+            ..self
+        }
+    }
+
+    /// Additionally stops the algorithm once the desired accuracy `epsilon` is
+    /// reached.
+    pub fn with_eps(self, epsilon: f64) -> Self {
+        TermCriteria {
+            epsilon: Some(epsilon),
+            ..self
+        }
+    }
+
+    /// Returns the maximum number of iterations, if limited.
+    pub fn max_count(&self) -> Option<i32> {
+        self.max_count
+    }
+
+    /// Returns the desired accuracy, if limited.
+    pub fn epsilon(&self) -> Option<f64> {
+        self.epsilon
+    }
+}
+
+impl From<TermCriteria> for ffi::TermCriteria {
+    fn from(criteria: TermCriteria) -> ffi::TermCriteria {
+        let mut type_ = 0;
+        if criteria.max_count.is_some() {
+            type_ |= TERM_CRITERIA_COUNT;
+        }
+        if criteria.epsilon.is_some() {
+            type_ |= TERM_CRITERIA_EPS;
+        }
+        ffi::TermCriteria {
+            type_,
+            maxCount: criteria.max_count.unwrap_or(0),
+            epsilon: criteria.epsilon.unwrap_or(0.0),
+        }
+    }
+}
+
+impl From<ffi::TermCriteria> for TermCriteria {
+    fn from(criteria: ffi::TermCriteria) -> TermCriteria {
+        TermCriteria {
+            max_count: if criteria.type_ & TERM_CRITERIA_COUNT != 0 {
+                Some(criteria.maxCount)
+            } else {
+                None
+            },
+            epsilon: if criteria.type_ & TERM_CRITERIA_EPS != 0 {
+                Some(criteria.epsilon)
+            } else {
+                None
+            },
+        }
+    }
 }
 
 /// Various border types, image boundaries are denoted with `|`.
@@ -111,25 +485,28 @@ impl Mat {
         }
     }
 
-    /// Creates an empty `Mat` struct with size.
-    pub fn new_with_size(rows: i32, cols: i32, t: CvType) -> Mat {
-        Mat {
-            inner: unsafe { ffi::Mat_NewWithSize(rows, cols, t as i32) },
-        }
+    /// Creates an empty `Mat` struct with size. Fails for negative sizes.
+    pub fn new_with_size(rows: i32, cols: i32, t: CvType) -> Result<Mat, Error> {
+        let mut inner = ptr::null_mut();
+        ::cv_result(unsafe { ffi::Mat_NewWithSize_Checked(rows, cols, t.to_i32(), &mut inner) })?;
+        Ok(Mat { inner })
     }
 
     /// Creates an empty `Mat` struct with a constant scalar.
-    pub fn new_from_scalar(s: Scalar, t: CvType) -> Mat {
-        Mat {
-            inner: unsafe { ffi::Mat_NewFromScalar(s, t as i32) },
-        }
+    pub fn new_from_scalar(s: Scalar, t: CvType) -> Result<Mat, Error> {
+        let mut inner = ptr::null_mut();
+        ::cv_result(unsafe { ffi::Mat_NewFromScalar_Checked(s, t.to_i32(), &mut inner) })?;
+        Ok(Mat { inner })
     }
 
-    /// Creates an empty `Mat` struct from buffer.
-    pub fn new_from_bytes(rows: i32, cols: i32, t: CvType, buf: &mut [i8]) -> Mat {
-        Mat {
-            inner: unsafe { ffi::Mat_NewFromBytes(rows, cols, t as i32, to_byte_array(buf)) },
-        }
+    /// Creates a `Mat` struct holding a copy of the buffer. Fails for
+    /// negative sizes or if the buffer is too small.
+    pub fn new_from_bytes(rows: i32, cols: i32, t: CvType, buf: &mut [i8]) -> Result<Mat, Error> {
+        let mut inner = ptr::null_mut();
+        ::cv_result(unsafe {
+            ffi::Mat_NewFromBytes_Checked(rows, cols, t.to_i32(), to_byte_array(buf), &mut inner)
+        })?;
+        Ok(Mat { inner })
     }
 
     /// Determines if the Mat is empty or not.
@@ -141,21 +518,21 @@ impl Mat {
     ///
     /// For further details, please see [OpenCV
     /// documentation](https://docs.opencv.org/master/d3/d63/classcv_1_1Mat.html#a33fd5d125b4c302b0c9aa86980791a77)
-    pub fn copy_to(&self, dst: &mut Mat) {
-        unsafe { ffi::Mat_CopyTo(self.inner, dst.inner) }
+    pub fn copy_to(&self, dst: &mut Mat) -> Result<(), Error> {
+        ::cv_result(unsafe { ffi::Mat_CopyTo_Checked(self.inner, dst.inner) })
     }
 
     /// Copies Mat into destination Mat, with a mask.
-    pub fn copy_to_with_mask(&self, dst: &mut Mat, mask: &Mat) {
-        unsafe { ffi::Mat_CopyToWithMask(self.inner, dst.inner, mask.inner) }
+    pub fn copy_to_with_mask(&self, dst: &mut Mat, mask: &Mat) -> Result<(), Error> {
+        ::cv_result(unsafe { ffi::Mat_CopyToWithMask_Checked(self.inner, dst.inner, mask.inner) })
     }
 
     /// Converts Mat into destination Mat.
     ///
     /// For further details, please see [OpenCV
     /// documentation](https://docs.opencv.org/master/d3/d63/classcv_1_1Mat.html#adf88c60c5b4980e05bb556080916978b)
-    pub fn convert_to(&self, dst: &mut Mat, t: CvType) {
-        unsafe { ffi::Mat_ConvertTo(self.inner, dst.inner, t as i32) }
+    pub fn convert_to(&self, dst: &mut Mat, t: CvType) -> Result<(), Error> {
+        ::cv_result(unsafe { ffi::Mat_ConvertTo_Checked(self.inner, dst.inner, t.to_i32()) })
     }
 
     /// Copies the underlying Mat data to a byte array.
@@ -167,18 +544,55 @@ impl Mat {
         from_byte_array(&array)
     }
 
-    /// Returns a new Mat that points to a region of this Mat. Changes made to
-    /// the region Mat will affect the original Mat, since they are pointers to
-    /// the underlying OpenCV Mat object.
-    pub fn region(&self, roi: Rect) -> Mat {
-        Mat::from(unsafe { ffi::Mat_Region(self.inner, roi) })
+    /// Copies the points stored in this Mat into a vector. The Mat has to hold
+    /// one point per element as `Cv32FC2`, e.g. the `corners` computed by
+    /// [good_features_to_track](../imgproc/fn.good_features_to_track.html),
+    /// or one point per row as a `Cv32FC1` Mat with two columns.
+    pub fn to_points2f(&self) -> Result<Vec<Point2f>, Error> {
+        if self.empty() {
+            return Ok(Vec::new());
+        }
+        let t = self.cv_type();
+        let n = if t == CvType::Cv32FC2 {
+            self.total()
+        } else if t == CvType::Cv32FC1 && self.dims() == 2 && self.cols() == 2 {
+            self.total() / 2
+        } else {
+            return Err(CvError::TypeMismatch {
+                expected: CvType::Cv32FC2,
+                actual: t,
+            }
+            .into());
+        };
+        let mut points = vec![Point2f { x: 0.0, y: 0.0 }; n];
+        ::cv_result(unsafe { ffi::Mat_ToPoints2f(self.inner, to_points2f(&mut points)) })?;
+        Ok(points)
+    }
+
+    /// Returns a view of a region of this Mat, without copying. The view
+    /// borrows this Mat; use [region_mut](#method.region_mut) to change the
+    /// pixels of the region.
+    pub fn region(&self, roi: Rect) -> Result<MatView<'_>, Error> {
+        let mut result = ptr::null_mut();
+        ::cv_result(unsafe { ffi::Mat_Region_Checked(self.inner, roi, &mut result) })?;
+        Ok(MatView::new(result))
+    }
+
+    /// Returns a mutable view of a region of this Mat. Changes made through
+    /// the view affect this Mat, since both share the same data.
+    pub fn region_mut(&mut self, roi: Rect) -> Result<MatViewMut<'_>, Error> {
+        let mut result = ptr::null_mut();
+        ::cv_result(unsafe { ffi::Mat_Region_Checked(self.inner, roi, &mut result) })?;
+        Ok(MatViewMut::new(result))
     }
 
     /// Changes the shape and/or the number of channels of a 2D matrix without
     /// copying the data. The method makes a new matrix header for the internal
-    /// data.
-    pub fn reshape(&self, channel: i32, rows: i32) -> Mat {
-        Mat::from(unsafe { ffi::Mat_Reshape(self.inner, channel, rows) })
+    /// data, which borrows this Mat.
+    pub fn reshape(&self, channel: i32, rows: i32) -> Result<MatView<'_>, Error> {
+        let mut result = ptr::null_mut();
+        ::cv_result(unsafe { ffi::Mat_Reshape_Checked(self.inner, channel, rows, &mut result) })?;
+        Ok(MatView::new(result))
     }
 
     /// Converts a Mat to half-precision floating point.
@@ -188,28 +602,57 @@ impl Mat {
     /// of CV_32F or CV_16S to represent the bit depth. If the input array is
     /// neither of them, the function will raise an error. The format of half
     /// precision floating point is defined in IEEE 754-2008.
-    pub fn convert_fp16(&self) -> Mat {
-        Mat::from(unsafe { ffi::Mat_ConvertFp16(self.inner) })
+    pub fn convert_fp16(&self) -> Result<Mat, Error> {
+        let mut result = ptr::null_mut();
+        ::cv_result(unsafe { ffi::Mat_ConvertFp16_Checked(self.inner, &mut result) })?;
+        Ok(Mat::from(result))
     }
 
     /// Calculates the mean value M of array elements, independently for each
     /// channel, and return it as Scalar.
-    /// TODO: pass second paramter with mask
-    pub fn mean(&self) -> Scalar {
-        unsafe { ffi::Mat_Mean(self.inner) }
+    pub fn mean(&self) -> Result<Scalar, Error> {
+        let mut result = Scalar {
+            val1: 0.0,
+            val2: 0.0,
+            val3: 0.0,
+            val4: 0.0,
+        };
+        ::cv_result(unsafe { ffi::Mat_Mean_Checked(self.inner, &mut result) })?;
+        Ok(result)
+    }
+
+    /// Calculates the mean value of the array elements selected by the 8-bit
+    /// `mask`, independently for each channel. The result is zero if the mask
+    /// selects no elements.
+    pub fn mean_with_mask(&self, mask: &Mat) -> Result<Scalar, Error> {
+        let mut result = Scalar {
+            val1: 0.0,
+            val2: 0.0,
+            val3: 0.0,
+            val4: 0.0,
+        };
+        ::cv_result(unsafe { ffi::Mat_MeanWithMask_Checked(self.inner, mask.inner, &mut result) })?;
+        Ok(result)
     }
 
     /// Calculates the per-channel pixel sum of an image.
-    pub fn sum(&self) -> Scalar {
-        unsafe { ffi::Mat_Sum(self.inner) }
+    pub fn sum(&self) -> Result<Scalar, Error> {
+        let mut result = Scalar {
+            val1: 0.0,
+            val2: 0.0,
+            val3: 0.0,
+            val4: 0.0,
+        };
+        ::cv_result(unsafe { ffi::Mat_Sum_Checked(self.inner, &mut result) })?;
+        Ok(result)
     }
 
     /// Performs a look-up table transform of an array.
     ///
     /// The function LUT fills the output array with values from the look-up table.
     /// Indices of the entries are taken from the input array.
-    pub fn lookup_table_transform(&self, table: &Mat, dst: &mut Mat) {
-        unsafe { ffi::LUT(self.inner, table.inner, dst.inner) }
+    pub fn lookup_table_transform(&self, table: &Mat, dst: &mut Mat) -> Result<(), Error> {
+        ::cv_result(unsafe { ffi::LUT_Checked(self.inner, table.inner, dst.inner) })
     }
 
     /// Returns the number of rows for this Mat.
@@ -229,8 +672,18 @@ impl Mat {
 
     /// Returns the type for this Mat.
     pub fn cv_type(&self) -> CvType {
-        let t = unsafe { ffi::Mat_Type(self.inner) };
-        CvType::from_i32(t).expect("Unknown CvType")
+        CvType::from_i32(unsafe { ffi::Mat_Type(self.inner) })
+    }
+
+    /// Returns the depth of the elements of this Mat.
+    pub fn depth(&self) -> Depth {
+        self.cv_type().depth()
+    }
+
+    /// Returns the size of an element of this Mat in bytes. For example, a
+    /// `Cv16SC3` Mat has 6 byte elements.
+    pub fn elem_size(&self) -> usize {
+        self.cv_type().elem_size()
     }
 
     /// Returns the number of bytes each matrix row occupies.
@@ -238,162 +691,542 @@ impl Mat {
         unsafe { ffi::Mat_Step(self.inner) }
     }
 
+    /// Returns the total number of elements of this Mat, i.e. the product of
+    /// its sizes in every dimension.
+    pub fn total(&self) -> usize {
+        unsafe { ffi::Mat_Total(self.inner) as usize }
+    }
+
+    /// Returns the number of dimensions of this Mat. It is 2 for images and
+    /// matrices, and larger for e.g. the blobs used by the `dnn` module.
+    pub fn dims(&self) -> i32 {
+        unsafe { ffi::Mat_Dims(self.inner) }
+    }
+
+    pub(crate) fn data_ptr(&self) -> *mut u8 {
+        unsafe { ffi::Mat_Data(self.inner) }
+    }
+
+    /// Returns true if the elements of this Mat are stored continuously
+    /// without gaps at the end of each row. A region of a larger Mat is
+    /// usually not continuous.
+    pub fn is_continuous(&self) -> bool {
+        unsafe { ffi::Mat_IsContinuous(self.inner) }
+    }
+
+    /// Returns the pixel data of this Mat as a byte slice, without copying.
+    ///
+    /// Returns `None` if the Mat is empty or not continuous; use
+    /// [data_row](#method.data_row) to access the rows of a region.
+    pub fn data(&self) -> Option<&[u8]> {
+        let len = self.data_len()?;
+        Some(unsafe { slice::from_raw_parts(self.data_ptr(), len) })
+    }
+
+    /// Mutable version of [data](#method.data).
+    pub fn data_mut(&mut self) -> Option<&mut [u8]> {
+        let len = self.data_len()?;
+        Some(unsafe { slice::from_raw_parts_mut(self.data_ptr(), len) })
+    }
+
+    /// Returns the pixel data of a single row as a byte slice, without
+    /// copying. Unlike [data](#method.data) this works for regions too, since
+    /// each row starts `cv_step()` bytes after the previous one.
+    ///
+    /// Returns `None` if the row is out of range.
+    pub fn data_row(&self, row: i32) -> Option<&[u8]> {
+        let (offset, len) = self.row_range(row)?;
+        Some(unsafe { slice::from_raw_parts(self.data_ptr().add(offset), len) })
+    }
+
+    /// Mutable version of [data_row](#method.data_row).
+    pub fn data_row_mut(&mut self, row: i32) -> Option<&mut [u8]> {
+        let (offset, len) = self.row_range(row)?;
+        Some(unsafe { slice::from_raw_parts_mut(self.data_ptr().add(offset), len) })
+    }
+
+    fn data_len(&self) -> Option<usize> {
+        if self.empty() || !self.is_continuous() {
+            return None;
+        }
+        Some(self.total() * self.elem_size())
+    }
+
+    fn row_range(&self, row: i32) -> Option<(usize, usize)> {
+        if self.empty() || row < 0 || row >= self.rows() || self.cols() < 0 {
+            return None;
+        }
+        let offset = row as usize * self.cv_step() as usize;
+        Some((offset, self.cols() as usize * self.elem_size()))
+    }
+
     /// Returns a value from a specific row/col in this Mat (must be CV_8U).
-    pub fn uchar_at(&self, row: i32, col: i32) -> u8 {
-        unsafe { ffi::Mat_GetUChar(self.inner, row, col) }
+    pub fn uchar_at(&self, row: i32, col: i32) -> Result<u8, Error> {
+        self.check_at(Depth::Cv8U, &[row, col])?;
+        Ok(unsafe { ffi::Mat_GetUChar(self.inner, row, col) })
     }
 
     /// Returns a value from a specific x, y, z in this Mat (must be CV_8U).
-    pub fn uchar_at3(&self, x: i32, y: i32, z: i32) -> u8 {
-        unsafe { ffi::Mat_GetUChar3(self.inner, x, y, z) }
+    pub fn uchar_at3(&self, x: i32, y: i32, z: i32) -> Result<u8, Error> {
+        self.check_at(Depth::Cv8U, &[x, y, z])?;
+        Ok(unsafe { ffi::Mat_GetUChar3(self.inner, x, y, z) })
     }
 
     /// Returns a value from a specific row/col in this Mat (must be CV_8S).
-    pub fn schar_at(&self, row: i32, col: i32) -> i8 {
-        unsafe { ffi::Mat_GetSChar(self.inner, row, col) }
+    pub fn schar_at(&self, row: i32, col: i32) -> Result<i8, Error> {
+        self.check_at(Depth::Cv8S, &[row, col])?;
+        Ok(unsafe { ffi::Mat_GetSChar(self.inner, row, col) })
     }
 
     /// Returns a value from a specific x, y, z in this Mat (must be CV_8S).
-    pub fn schar_at3(&self, x: i32, y: i32, z: i32) -> i8 {
-        unsafe { ffi::Mat_GetSChar3(self.inner, x, y, z) }
+    pub fn schar_at3(&self, x: i32, y: i32, z: i32) -> Result<i8, Error> {
+        self.check_at(Depth::Cv8S, &[x, y, z])?;
+        Ok(unsafe { ffi::Mat_GetSChar3(self.inner, x, y, z) })
     }
 
     /// Returns a value from a specific row/col in this Mat (must be CV_16S).
-    pub fn short_at(&self, row: i32, col: i32) -> i16 {
-        unsafe { ffi::Mat_GetShort(self.inner, row, col) }
+    pub fn short_at(&self, row: i32, col: i32) -> Result<i16, Error> {
+        self.check_at(Depth::Cv16S, &[row, col])?;
+        Ok(unsafe { ffi::Mat_GetShort(self.inner, row, col) })
     }
 
     /// Returns a value from a specific x, y, z in this Mat (must be CV_16S).
-    pub fn short_at3(&self, x: i32, y: i32, z: i32) -> i16 {
-        unsafe { ffi::Mat_GetShort3(self.inner, x, y, z) }
+    pub fn short_at3(&self, x: i32, y: i32, z: i32) -> Result<i16, Error> {
+        self.check_at(Depth::Cv16S, &[x, y, z])?;
+        Ok(unsafe { ffi::Mat_GetShort3(self.inner, x, y, z) })
     }
 
     /// Returns a value from a specific row/col in this Mat (must be CV_32S).
-    pub fn int_at(&self, row: i32, col: i32) -> i32 {
-        unsafe { ffi::Mat_GetInt(self.inner, row, col) }
+    pub fn int_at(&self, row: i32, col: i32) -> Result<i32, Error> {
+        self.check_at(Depth::Cv32S, &[row, col])?;
+        Ok(unsafe { ffi::Mat_GetInt(self.inner, row, col) })
     }
 
     /// Returns a value from a specific x, y, z in this Mat (must be CV_32S).
-    pub fn int_at3(&self, x: i32, y: i32, z: i32) -> i32 {
-        unsafe { ffi::Mat_GetInt3(self.inner, x, y, z) }
+    pub fn int_at3(&self, x: i32, y: i32, z: i32) -> Result<i32, Error> {
+        self.check_at(Depth::Cv32S, &[x, y, z])?;
+        Ok(unsafe { ffi::Mat_GetInt3(self.inner, x, y, z) })
     }
 
     /// Returns a value from a specific row/col in this Mat (must be CV_32F).
-    pub fn float_at(&self, row: i32, col: i32) -> f32 {
-        unsafe { ffi::Mat_GetFloat(self.inner, row, col) }
+    pub fn float_at(&self, row: i32, col: i32) -> Result<f32, Error> {
+        self.check_at(Depth::Cv32F, &[row, col])?;
+        Ok(unsafe { ffi::Mat_GetFloat(self.inner, row, col) })
     }
 
     /// Returns a value from a specific x, y, z in this Mat (must be CV_32F).
-    pub fn float_at3(&self, x: i32, y: i32, z: i32) -> f32 {
-        unsafe { ffi::Mat_GetFloat3(self.inner, x, y, z) }
+    pub fn float_at3(&self, x: i32, y: i32, z: i32) -> Result<f32, Error> {
+        self.check_at(Depth::Cv32F, &[x, y, z])?;
+        Ok(unsafe { ffi::Mat_GetFloat3(self.inner, x, y, z) })
     }
 
     /// Returns a value from a specific row/col in this Mat (must be CV_64F).
-    pub fn double_at(&self, row: i32, col: i32) -> f64 {
-        unsafe { ffi::Mat_GetDouble(self.inner, row, col) }
+    pub fn double_at(&self, row: i32, col: i32) -> Result<f64, Error> {
+        self.check_at(Depth::Cv64F, &[row, col])?;
+        Ok(unsafe { ffi::Mat_GetDouble(self.inner, row, col) })
     }
 
     /// Returns a value from a specific x, y, z in this Mat (must be CV_64F).
-    pub fn double_at3(&self, x: i32, y: i32, z: i32) -> f64 {
-        unsafe { ffi::Mat_GetDouble3(self.inner, x, y, z) }
+    pub fn double_at3(&self, x: i32, y: i32, z: i32) -> Result<f64, Error> {
+        self.check_at(Depth::Cv64F, &[x, y, z])?;
+        Ok(unsafe { ffi::Mat_GetDouble3(self.inner, x, y, z) })
     }
 
     /// Sets a value to a specific row/col in this Mat (must be CV_8U).
-    pub fn set_uchar_at(&mut self, row: i32, col: i32, val: u8) {
-        unsafe { ffi::Mat_SetUChar(self.inner, row, col, val) }
+    pub fn set_uchar_at(&mut self, row: i32, col: i32, val: u8) -> Result<(), Error> {
+        self.check_at(Depth::Cv8U, &[row, col])?;
+        unsafe { ffi::Mat_SetUChar(self.inner, row, col, val) };
+        Ok(())
     }
 
     /// Sets a value to a specific x, y, z in this Mat (must be CV_8U).
-    pub fn set_uchar_at3(&mut self, x: i32, y: i32, z: i32, val: u8) {
-        unsafe { ffi::Mat_SetUChar3(self.inner, x, y, z, val) }
+    pub fn set_uchar_at3(&mut self, x: i32, y: i32, z: i32, val: u8) -> Result<(), Error> {
+        self.check_at(Depth::Cv8U, &[x, y, z])?;
+        unsafe { ffi::Mat_SetUChar3(self.inner, x, y, z, val) };
+        Ok(())
     }
 
     /// Sets a value to a specific row/col in this Mat (must be CV_8S).
-    pub fn set_schar_at(&mut self, row: i32, col: i32, val: i8) {
-        unsafe { ffi::Mat_SetSChar(self.inner, row, col, val) }
+    pub fn set_schar_at(&mut self, row: i32, col: i32, val: i8) -> Result<(), Error> {
+        self.check_at(Depth::Cv8S, &[row, col])?;
+        unsafe { ffi::Mat_SetSChar(self.inner, row, col, val) };
+        Ok(())
     }
 
     /// Sets a value to a specific x, y, z in this Mat (must be CV_8S).
-    pub fn set_schar_at3(&mut self, x: i32, y: i32, z: i32, val: i8) {
-        unsafe { ffi::Mat_SetSChar3(self.inner, x, y, z, val) }
+    pub fn set_schar_at3(&mut self, x: i32, y: i32, z: i32, val: i8) -> Result<(), Error> {
+        self.check_at(Depth::Cv8S, &[x, y, z])?;
+        unsafe { ffi::Mat_SetSChar3(self.inner, x, y, z, val) };
+        Ok(())
     }
 
     /// Sets a value to a specific row/col in this Mat (must be CV_16S).
-    pub fn set_short_at(&mut self, row: i32, col: i32, val: i16) {
-        unsafe { ffi::Mat_SetShort(self.inner, row, col, val) }
+    pub fn set_short_at(&mut self, row: i32, col: i32, val: i16) -> Result<(), Error> {
+        self.check_at(Depth::Cv16S, &[row, col])?;
+        unsafe { ffi::Mat_SetShort(self.inner, row, col, val) };
+        Ok(())
     }
 
     /// Sets a value to a specific x, y, z in this Mat (must be CV_16S).
-    pub fn set_short_at3(&mut self, x: i32, y: i32, z: i32, val: i16) {
-        unsafe { ffi::Mat_SetShort3(self.inner, x, y, z, val) }
+    pub fn set_short_at3(&mut self, x: i32, y: i32, z: i32, val: i16) -> Result<(), Error> {
+        self.check_at(Depth::Cv16S, &[x, y, z])?;
+        unsafe { ffi::Mat_SetShort3(self.inner, x, y, z, val) };
+        Ok(())
     }
 
     /// Sets a value to a specific row/col in this Mat (must be CV_32S).
-    pub fn set_int_at(&mut self, row: i32, col: i32, val: i32) {
-        unsafe { ffi::Mat_SetInt(self.inner, row, col, val) }
+    pub fn set_int_at(&mut self, row: i32, col: i32, val: i32) -> Result<(), Error> {
+        self.check_at(Depth::Cv32S, &[row, col])?;
+        unsafe { ffi::Mat_SetInt(self.inner, row, col, val) };
+        Ok(())
     }
 
     /// Sets a value to a specific x, y, z in this Mat (must be CV_32S).
-    pub fn set_int_at3(&mut self, x: i32, y: i32, z: i32, val: i32) {
-        unsafe { ffi::Mat_SetInt3(self.inner, x, y, z, val) }
+    pub fn set_int_at3(&mut self, x: i32, y: i32, z: i32, val: i32) -> Result<(), Error> {
+        self.check_at(Depth::Cv32S, &[x, y, z])?;
+        unsafe { ffi::Mat_SetInt3(self.inner, x, y, z, val) };
+        Ok(())
     }
 
     /// Sets a value to a specific row/col in this Mat (must be CV_32F).
-    pub fn set_float_at(&mut self, row: i32, col: i32, val: f32) {
-        unsafe { ffi::Mat_SetFloat(self.inner, row, col, val) }
+    pub fn set_float_at(&mut self, row: i32, col: i32, val: f32) -> Result<(), Error> {
+        self.check_at(Depth::Cv32F, &[row, col])?;
+        unsafe { ffi::Mat_SetFloat(self.inner, row, col, val) };
+        Ok(())
     }
 
     /// Sets a value to a specific x, y, z in this Mat (must be CV_32F).
-    pub fn set_float_at3(&mut self, x: i32, y: i32, z: i32, val: f32) {
-        unsafe { ffi::Mat_SetFloat3(self.inner, x, y, z, val) }
+    pub fn set_float_at3(&mut self, x: i32, y: i32, z: i32, val: f32) -> Result<(), Error> {
+        self.check_at(Depth::Cv32F, &[x, y, z])?;
+        unsafe { ffi::Mat_SetFloat3(self.inner, x, y, z, val) };
+        Ok(())
     }
 
     /// Sets a value to a specific row/col in this Mat (must be CV_64F).
-    pub fn set_double_at(&mut self, row: i32, col: i32, val: f64) {
-        unsafe { ffi::Mat_SetDouble(self.inner, row, col, val) }
+    pub fn set_double_at(&mut self, row: i32, col: i32, val: f64) -> Result<(), Error> {
+        self.check_at(Depth::Cv64F, &[row, col])?;
+        unsafe { ffi::Mat_SetDouble(self.inner, row, col, val) };
+        Ok(())
     }
 
     /// Sets a value to a specific x, y, z in this Mat (must be CV_64F).
-    pub fn set_double_at3(&mut self, x: i32, y: i32, z: i32, val: f64) {
-        unsafe { ffi::Mat_SetDouble3(self.inner, x, y, z, val) }
+    pub fn set_double_at3(&mut self, x: i32, y: i32, z: i32, val: f64) -> Result<(), Error> {
+        self.check_at(Depth::Cv64F, &[x, y, z])?;
+        unsafe { ffi::Mat_SetDouble3(self.inner, x, y, z, val) };
+        Ok(())
+    }
+
+    /// Checks that an element of the given depth can be accessed at `index`,
+    /// which holds one coordinate per dimension. Like `cv::Mat::at`, the
+    /// column of a 2D Mat counts single channel values.
+    fn check_at(&self, depth: Depth, index: &[i32]) -> Result<(), Error> {
+        let t = self.cv_type();
+        if t.depth() != depth {
+            return Err(CvError::TypeMismatch {
+                expected: CvType::new(depth, t.channels()),
+                actual: t,
+            }
+            .into());
+        }
+
+        let dims = self.dims();
+        let mut shape: Vec<i32> = (0..dims)
+            .map(|i| unsafe { ffi::Mat_SizeAt(self.inner, i) })
+            .collect();
+        if dims == 2 {
+            shape[1] *= t.channels();
+        }
+        let inside =
+            shape.len() == index.len() && index.iter().zip(&shape).all(|(&i, &n)| i >= 0 && i < n);
+        if !inside {
+            return Err(CvError::OutOfBounds {
+                index: index.to_vec(),
+                shape,
+            }
+            .into());
+        }
+        Ok(())
+    }
+}
+
+/// A Mat header sharing the data of another Mat, returned by
+/// [Mat::region](struct.Mat.html#method.region) and
+/// [Mat::reshape](struct.Mat.html#method.reshape).
+///
+/// The view borrows the Mat it was made from, so that Mat cannot be changed
+/// while the view is alive. It dereferences to `Mat` for reading; `clone`
+/// copies the data into a new, independent Mat.
+#[derive(Debug)]
+pub struct MatView<'a> {
+    mat: Mat,
+    _parent: PhantomData<&'a Mat>,
+}
+
+impl<'a> MatView<'a> {
+    fn new(inner: ffi::Mat) -> Self {
+        MatView {
+            mat: Mat::from(inner),
+            _parent: PhantomData,
+        }
+    }
+}
+
+impl<'a> Deref for MatView<'a> {
+    type Target = Mat;
+
+    fn deref(&self) -> &Mat {
+        &self.mat
+    }
+}
+
+/// A mutable Mat header sharing the data of another Mat, returned by
+/// [Mat::region_mut](struct.Mat.html#method.region_mut).
+///
+/// Like [MatView](struct.MatView.html) it dereferences to `Mat` for reading
+/// only, since a `&mut Mat` would allow swapping the header out of the
+/// borrow. The shared pixels are written with
+/// [data_mut](#method.data_mut) and [data_row_mut](#method.data_row_mut).
+#[derive(Debug)]
+pub struct MatViewMut<'a> {
+    mat: Mat,
+    _parent: PhantomData<&'a mut Mat>,
+}
+
+impl<'a> MatViewMut<'a> {
+    fn new(inner: ffi::Mat) -> Self {
+        MatViewMut {
+            mat: Mat::from(inner),
+            _parent: PhantomData,
+        }
+    }
+
+    /// Mutable version of [Mat::data](struct.Mat.html#method.data).
+    pub fn data_mut(&mut self) -> Option<&mut [u8]> {
+        self.mat.data_mut()
+    }
+
+    /// Mutable version of [Mat::data_row](struct.Mat.html#method.data_row).
+    pub fn data_row_mut(&mut self, row: i32) -> Option<&mut [u8]> {
+        self.mat.data_row_mut(row)
+    }
+}
+
+impl<'a> Deref for MatViewMut<'a> {
+    type Target = Mat;
+
+    fn deref(&self) -> &Mat {
+        &self.mat
+    }
+}
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// Types that can be stored as the elements of a [TypedMat](struct.TypedMat.html):
+/// the primitive types matching a [Depth](enum.Depth.html) for single
+/// channel Mats, and arrays of them for multi-channel Mats (e.g. `[u8; 3]`
+/// for a BGR image).
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait Element: Copy + private::Sealed {
+    /// Depth of a single channel of the element.
+    const DEPTH: Depth;
+    /// Number of channels of the element.
+    const CHANNELS: i32;
+
+    /// Returns the type of a Mat holding elements of this type.
+    fn cv_type() -> CvType {
+        CvType::new(Self::DEPTH, Self::CHANNELS)
+    }
+}
+
+macro_rules! impl_element {
+    ($t:ty, $depth:expr) => {
+        impl private::Sealed for $t {}
+        impl Element for $t {
+            const DEPTH: Depth = $depth;
+            const CHANNELS: i32 = 1;
+        }
+
+        impl_element!(@array $t, 2);
+        impl_element!(@array $t, 3);
+        impl_element!(@array $t, 4);
+    };
+    (@array $t:ty, $n:expr) => {
+        impl private::Sealed for [$t; $n] {}
+        impl Element for [$t; $n] {
+            const DEPTH: Depth = <$t as Element>::DEPTH;
+            const CHANNELS: i32 = $n;
+        }
+    };
+}
+
+impl_element!(u8, Depth::Cv8U);
+impl_element!(i8, Depth::Cv8S);
+impl_element!(u16, Depth::Cv16U);
+impl_element!(i16, Depth::Cv16S);
+impl_element!(i32, Depth::Cv32S);
+impl_element!(f32, Depth::Cv32F);
+impl_element!(f64, Depth::Cv64F);
+
+/// A [Mat](struct.Mat.html) whose element type is checked once, when it is
+/// created, so that its elements can be accessed without naming the depth at
+/// every call.
+///
+/// A `TypedMat` dereferences to the untyped `Mat`, so it can be passed to
+/// every function taking a `&Mat`. It cannot be used as an output argument,
+/// since that could change its type; convert it back with
+/// [into_mat](#method.into_mat) instead.
+#[derive(Debug, Clone)]
+pub struct TypedMat<T: Element> {
+    mat: Mat,
+    _marker: PhantomData<T>,
+}
+
+impl<T: Element> TypedMat<T> {
+    /// Creates a Mat with the given size, with every element set to zero.
+    pub fn new(rows: i32, cols: i32) -> Result<Self, Error> {
+        let mut mat = Mat::new_with_size(rows, cols, T::cv_type())?;
+        if let Some(data) = mat.data_mut() {
+            for b in data.iter_mut() {
+                *b = 0;
+            }
+        }
+        Ok(TypedMat {
+            mat,
+            _marker: PhantomData,
+        })
+    }
+
+    /// Wraps an untyped Mat, checking that its elements are of type `T`.
+    pub fn from_mat(mat: Mat) -> Result<Self, Error> {
+        let actual = mat.cv_type();
+        if actual != T::cv_type() {
+            return Err(CvError::TypeMismatch {
+                expected: T::cv_type(),
+                actual,
+            }
+            .into());
+        }
+        Ok(TypedMat {
+            mat,
+            _marker: PhantomData,
+        })
+    }
+
+    /// Returns the untyped Mat.
+    pub fn into_mat(self) -> Mat {
+        self.mat
+    }
+
+    /// Returns the elements of a row, or `None` if the row is out of range.
+    pub fn row(&self, row: i32) -> Option<&[T]> {
+        let cols = self.mat.cols() as usize;
+        let data = self.mat.data_row(row)?;
+        Some(unsafe { slice::from_raw_parts(data.as_ptr() as *const T, cols) })
+    }
+
+    /// Mutable version of [row](#method.row).
+    pub fn row_mut(&mut self, row: i32) -> Option<&mut [T]> {
+        let cols = self.mat.cols() as usize;
+        let data = self.mat.data_row_mut(row)?;
+        Some(unsafe { slice::from_raw_parts_mut(data.as_mut_ptr() as *mut T, cols) })
+    }
+
+    /// Returns the element at the given position, or `None` if the position
+    /// is out of range.
+    pub fn at(&self, row: i32, col: i32) -> Option<&T> {
+        if col < 0 {
+            return None;
+        }
+        self.row(row)?.get(col as usize)
+    }
+
+    /// Mutable version of [at](#method.at).
+    pub fn at_mut(&mut self, row: i32, col: i32) -> Option<&mut T> {
+        if col < 0 {
+            return None;
+        }
+        self.row_mut(row)?.get_mut(col as usize)
+    }
+}
+
+impl<T: Element> Deref for TypedMat<T> {
+    type Target = Mat;
+
+    fn deref(&self) -> &Mat {
+        &self.mat
+    }
+}
+
+impl<T: Element> From<TypedMat<T>> for Mat {
+    fn from(mat: TypedMat<T>) -> Mat {
+        mat.into_mat()
     }
 }
 
 /// Calculates the per-element absolute difference between two arrays or
 /// between an array and a scalar.
-pub fn abs_diff(this: &Mat, other: &Mat, dst: &mut Mat) {
-    unsafe { ffi::Mat_AbsDiff(this.inner, other.inner, dst.inner) }
+pub fn abs_diff(this: &Mat, other: &Mat, dst: &mut Mat) -> Result<(), Error> {
+    ::cv_result(unsafe { ffi::Mat_AbsDiff_Checked(this.inner, other.inner, dst.inner) })
 }
 
-/// Calculates the per-element sum of two arrays or an array and a scalar.
-pub fn add(this: &Mat, other: &Mat, dst: &mut Mat) {
-    unsafe { ffi::Mat_AbsDiff(this.inner, other.inner, dst.inner) }
+/// Calculates the per-element sum of two arrays (dst = src1 + src2).
+///
+/// Only the elements selected by the optional 8-bit `mask` are changed.
+/// `dtype` is the depth of the output, by default the depth of the inputs.
+pub fn add(
+    src1: &Mat,
+    src2: &Mat,
+    dst: &mut Mat,
+    mask: Option<&Mat>,
+    dtype: Option<Depth>,
+) -> Result<(), Error> {
+    ::cv_result(unsafe {
+        ffi::Mat_Add_Checked(
+            src1.inner,
+            src2.inner,
+            dst.inner,
+            optional_mat(mask),
+            optional_depth(dtype),
+        )
+    })
 }
 
 /// Calculates the weighted sum of two arrays (dst = src1\*alpha + src2\*beta +
 /// gamma).
-pub fn add_weighted(src1: &Mat, alpha: f64, src2: &Mat, beta: f64, gamma: f64, dst: &mut Mat) {
-    unsafe { ffi::Mat_AddWeighted(src1.inner, alpha, src2.inner, beta, gamma, dst.inner) }
+pub fn add_weighted(
+    src1: &Mat,
+    alpha: f64,
+    src2: &Mat,
+    beta: f64,
+    gamma: f64,
+    dst: &mut Mat,
+) -> Result<(), Error> {
+    ::cv_result(unsafe {
+        ffi::Mat_AddWeighted_Checked(src1.inner, alpha, src2.inner, beta, gamma, dst.inner)
+    })
 }
 
 /// Computes bitwise conjunction of the two arrays (dst = src1 & src2).
-pub fn bitwise_and(src1: &Mat, src2: &Mat, dst: &mut Mat) {
-    unsafe { ffi::Mat_BitwiseAnd(src1.inner, src2.inner, dst.inner) }
+pub fn bitwise_and(src1: &Mat, src2: &Mat, dst: &mut Mat) -> Result<(), Error> {
+    ::cv_result(unsafe { ffi::Mat_BitwiseAnd_Checked(src1.inner, src2.inner, dst.inner) })
 }
 
 /// Inverts every bit of an array (dst = !src).
-pub fn bitwise_not(src: &Mat, dst: &mut Mat) {
-    unsafe { ffi::Mat_BitwiseNot(src.inner, dst.inner) }
+pub fn bitwise_not(src: &Mat, dst: &mut Mat) -> Result<(), Error> {
+    ::cv_result(unsafe { ffi::Mat_BitwiseNot_Checked(src.inner, dst.inner) })
 }
 
 /// Computes bitwise disjunction of the two arrays (dst = src1 | src2).
-pub fn bitwise_or(src1: &Mat, src2: &Mat, dst: &mut Mat) {
-    unsafe { ffi::Mat_BitwiseOr(src1.inner, src2.inner, dst.inner) }
+pub fn bitwise_or(src1: &Mat, src2: &Mat, dst: &mut Mat) -> Result<(), Error> {
+    ::cv_result(unsafe { ffi::Mat_BitwiseOr_Checked(src1.inner, src2.inner, dst.inner) })
 }
 
 /// Computes bitwise "exclusive or" of the two arrays (dst = src1 ^ src2).
-pub fn bitwise_xor(src1: &Mat, src2: &Mat, dst: &mut Mat) {
-    unsafe { ffi::Mat_BitwiseXor(src1.inner, src2.inner, dst.inner) }
+pub fn bitwise_xor(src1: &Mat, src2: &Mat, dst: &mut Mat) -> Result<(), Error> {
+    ::cv_result(unsafe { ffi::Mat_BitwiseXor_Checked(src1.inner, src2.inner, dst.inner) })
 }
 
 /// A naive nearest neighbor finder.
@@ -408,26 +1241,20 @@ pub fn batch_distance(
     mask: &Mat,
     update: i32,
     crosscheck: bool,
-) {
-    unsafe {
-        ffi::Mat_BatchDistance(
-            src1.inner,
-            src2.inner,
-            dist.inner,
-            dtype,
-            nidx.inner,
-            norm_type,
-            k,
-            mask.inner,
-            update,
-            crosscheck,
+) -> Result<(), Error> {
+    ::cv_result(unsafe {
+        ffi::Mat_BatchDistance_Checked(
+            src1.inner, src2.inner, dist.inner, dtype, nidx.inner, norm_type, k, mask.inner,
+            update, crosscheck,
         )
-    }
+    })
 }
 
 /// Computes the source location of an extrapolated pixel.
-pub fn border_interpolate(p: i32, len: i32, t: BorderType) -> i32 {
-    unsafe { ffi::Mat_BorderInterpolate(p, len, t as i32) }
+pub fn border_interpolate(p: i32, len: i32, t: BorderType) -> Result<i32, Error> {
+    let mut result = 0;
+    ::cv_result(unsafe { ffi::Mat_BorderInterpolate_Checked(p, len, t as i32, &mut result) })?;
+    Ok(result)
 }
 
 /// [Covariation flags](https://docs.opencv.org/master/d0/de1/group__core.html#ga719ebd4a73f30f4fab258ab7616d0f0f).
@@ -478,13 +1305,129 @@ pub fn calc_covar_matrix(
     mean: &mut Mat,
     flags: CovarFlag,
     ctype: i32,
-) {
-    unsafe { ffi::Mat_CalcCovarMatrix(samples.inner, covar.inner, mean.inner, flags as i32, ctype) }
+) -> Result<(), Error> {
+    ::cv_result(unsafe {
+        ffi::Mat_CalcCovarMatrix_Checked(
+            samples.inner,
+            covar.inner,
+            mean.inner,
+            flags as i32,
+            ctype,
+        )
+    })
+}
+
+/// How the vectors analysed by a [Pca](struct.Pca.html) are stored in the
+/// data matrix.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PcaLayout {
+    /// The vectors are the rows of the matrix.
+    Rows,
+    /// The vectors are the columns of the matrix.
+    Cols,
+}
+
+/// Number of principal components kept by a [Pca](struct.Pca.html).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PcaRetain {
+    /// Keeps at most the given number of components, or all of them for 0.
+    Components(i32),
+    /// Keeps the smallest number of components whose eigenvalues add up to
+    /// at least the given fraction (between 0 and 1) of the total variance.
+    Variance(f64),
+}
+
+/// Principal component analysis of a set of vectors.
+///
+/// The matrix getters return copies, so the analysis cannot be changed
+/// through them.
+#[derive(Debug)]
+pub struct Pca {
+    inner: ffi::PCA,
+}
+
+impl Drop for Pca {
+    fn drop(&mut self) {
+        unsafe { ffi::PCA_Close(self.inner) }
+    }
+}
+
+impl Pca {
+    /// Performs the analysis of the vectors stored in `data`.
+    ///
+    /// * `layout`: whether the vectors are the rows or the columns of `data`.
+    /// * `mean`: pre-computed mean vector, a single row or column like the
+    ///   vectors. It is computed from the data if `None`.
+    /// * `retain`: number of components to keep.
+    pub fn new(
+        data: &Mat,
+        layout: PcaLayout,
+        mean: Option<&Mat>,
+        retain: PcaRetain,
+    ) -> Result<Pca, Error> {
+        let flags = match layout {
+            PcaLayout::Rows => 0,
+            PcaLayout::Cols => 1,
+        };
+        let mut inner = ptr::null_mut();
+        ::cv_result(unsafe {
+            match retain {
+                PcaRetain::Components(n) => {
+                    ffi::PCA_New(data.inner, optional_mat(mean), flags, n, &mut inner)
+                }
+                PcaRetain::Variance(v) => {
+                    ffi::PCA_NewWithVariance(data.inner, optional_mat(mean), flags, v, &mut inner)
+                }
+            }
+        })?;
+        Ok(Pca { inner })
+    }
+
+    /// Projects vectors, laid out like the data, to the subspace of the
+    /// principal components.
+    pub fn project(&self, vec: &Mat) -> Result<Mat, Error> {
+        let result = Mat::new();
+        ::cv_result(unsafe { ffi::PCA_Project(self.inner, vec.inner, result.inner) })?;
+        Ok(result)
+    }
+
+    /// Reconstructs vectors from their projections, the inverse of
+    /// [project](#method.project) up to the discarded components.
+    pub fn back_project(&self, vec: &Mat) -> Result<Mat, Error> {
+        let result = Mat::new();
+        ::cv_result(unsafe { ffi::PCA_BackProject(self.inner, vec.inner, result.inner) })?;
+        Ok(result)
+    }
+
+    /// Returns the mean vector of the data.
+    pub fn mean(&self) -> Mat {
+        Mat::from(unsafe { ffi::PCA_GetMean(self.inner) }).clone()
+    }
+
+    /// Returns the eigenvalues of the covariance matrix, as a column vector
+    /// in descending order.
+    pub fn eigenvalues(&self) -> Mat {
+        Mat::from(unsafe { ffi::PCA_GetEigenvalues(self.inner) }).clone()
+    }
+
+    /// Returns the eigenvectors of the covariance matrix, i.e. the principal
+    /// components, as rows in the order of the eigenvalues.
+    pub fn eigenvectors(&self) -> Mat {
+        Mat::from(unsafe { ffi::PCA_GetEigenvectors(self.inner) }).clone()
+    }
 }
 
 /// Calculates the magnitude and angle of 2D vectors.
-pub fn cart_to_polar(x: &Mat, y: &Mat, magnitude: &mut Mat, angle: &mut Mat, use_degree: bool) {
-    unsafe { ffi::Mat_CartToPolar(x.inner, y.inner, magnitude.inner, angle.inner, use_degree) }
+pub fn cart_to_polar(
+    x: &Mat,
+    y: &Mat,
+    magnitude: &mut Mat,
+    angle: &mut Mat,
+    use_degree: bool,
+) -> Result<(), Error> {
+    ::cv_result(unsafe {
+        ffi::Mat_CartToPolar_Checked(x.inner, y.inner, magnitude.inner, angle.inner, use_degree)
+    })
 }
 
 /// Comparison type.
@@ -507,23 +1450,25 @@ pub enum CompareType {
 
 /// Performs the per-element comparison of two arrays or an array and scalar
 /// value.
-pub fn compare(src1: &Mat, src2: &Mat, dst: &mut Mat, ct: CompareType) {
-    unsafe { ffi::Mat_Compare(src1.inner, src2.inner, dst.inner, ct as i32) }
+pub fn compare(src1: &Mat, src2: &Mat, dst: &mut Mat, ct: CompareType) -> Result<(), Error> {
+    ::cv_result(unsafe { ffi::Mat_Compare_Checked(src1.inner, src2.inner, dst.inner, ct as i32) })
 }
 
 /// Counts non-zero array elements.
-pub fn count_non_zero(src: &Mat) -> i32 {
-    unsafe { ffi::Mat_CountNonZero(src.inner) }
+pub fn count_non_zero(src: &Mat) -> Result<i32, Error> {
+    let mut result = 0;
+    ::cv_result(unsafe { ffi::Mat_CountNonZero_Checked(src.inner, &mut result) })?;
+    Ok(result)
 }
 
 /// Copies the lower or the upper half of a square matrix to its another half.
-pub fn complete_symm(m: &mut Mat, lower_to_upper: bool) {
-    unsafe { ffi::Mat_CompleteSymm(m.inner, lower_to_upper) }
+pub fn complete_symm(m: &mut Mat, lower_to_upper: bool) -> Result<(), Error> {
+    ::cv_result(unsafe { ffi::Mat_CompleteSymm_Checked(m.inner, lower_to_upper) })
 }
 
 /// Scales, calculates absolute values, and converts the result to 8-bit.
-pub fn convert_scale_abs(src: &Mat, dst: &mut Mat, alpha: f64, beta: f64) {
-    unsafe { ffi::Mat_ConvertScaleAbs(src.inner, dst.inner, alpha, beta) }
+pub fn convert_scale_abs(src: &Mat, dst: &mut Mat, alpha: f64, beta: f64) -> Result<(), Error> {
+    ::cv_result(unsafe { ffi::Mat_ConvertScaleAbs_Checked(src.inner, dst.inner, alpha, beta) })
 }
 
 /// Forms a border around an image.
@@ -543,9 +1488,9 @@ pub fn copy_make_border(
     right: i32,
     type_: BorderType,
     value: Scalar,
-) {
-    unsafe {
-        ffi::Mat_CopyMakeBorder(
+) -> Result<(), Error> {
+    ::cv_result(unsafe {
+        ffi::Mat_CopyMakeBorder_Checked(
             src.inner,
             dst.inner,
             top,
@@ -555,17 +1500,757 @@ pub fn copy_make_border(
             type_ as i32,
             value,
         )
-    }
+    })
 }
 
 /// Finds the global minimum and maximum in an array.
-pub fn min_max_loc(input: &Mat) -> (f64, f64, Point, Point) {
+pub fn min_max_loc(input: &Mat) -> Result<(f64, f64, Point, Point), Error> {
+    let mut min = 0.0;
+    let mut max = 0.0;
+    let mut min_loc = Point { x: 0, y: 0 };
+    let mut max_loc = Point { x: 0, y: 0 };
+    ::cv_result(unsafe {
+        ffi::Mat_MinMaxLoc_Checked(input.inner, &mut min, &mut max, &mut min_loc, &mut max_loc)
+    })?;
+    Ok((min, max, min_loc, max_loc))
+}
+
+/// Calculates the per-element difference between two arrays (dst = src1 -
+/// src2).
+///
+/// Only the elements selected by the optional 8-bit `mask` are changed.
+/// `dtype` is the depth of the output, by default the depth of the inputs.
+pub fn subtract(
+    src1: &Mat,
+    src2: &Mat,
+    dst: &mut Mat,
+    mask: Option<&Mat>,
+    dtype: Option<Depth>,
+) -> Result<(), Error> {
+    ::cv_result(unsafe {
+        ffi::Mat_Subtract_Checked(
+            src1.inner,
+            src2.inner,
+            dst.inner,
+            optional_mat(mask),
+            optional_depth(dtype),
+        )
+    })
+}
+
+/// Calculates the per-element scaled product of two arrays (dst = scale \*
+/// src1 \* src2).
+///
+/// `dtype` is the depth of the output, by default the depth of the inputs.
+pub fn multiply(
+    src1: &Mat,
+    src2: &Mat,
+    dst: &mut Mat,
+    scale: f64,
+    dtype: Option<Depth>,
+) -> Result<(), Error> {
+    ::cv_result(unsafe {
+        ffi::Mat_Multiply_Checked(
+            src1.inner,
+            src2.inner,
+            dst.inner,
+            scale,
+            optional_depth(dtype),
+        )
+    })
+}
+
+/// Performs the per-element division of two arrays (dst = scale \* src1 /
+/// src2). Divisions by zero result in zero.
+///
+/// `dtype` is the depth of the output, by default the depth of the inputs.
+pub fn divide(
+    src1: &Mat,
+    src2: &Mat,
+    dst: &mut Mat,
+    scale: f64,
+    dtype: Option<Depth>,
+) -> Result<(), Error> {
+    ::cv_result(unsafe {
+        ffi::Mat_Divide_Checked(
+            src1.inner,
+            src2.inner,
+            dst.inner,
+            scale,
+            optional_depth(dtype),
+        )
+    })
+}
+
+/// Calculates the sum of a scaled array and another array (dst = alpha \*
+/// src1 + src2).
+///
+/// OpenCV supports neither a mask nor an output depth here; combine
+/// [multiply](fn.multiply.html) and [add](fn.add.html) when either is needed.
+pub fn scale_add(src1: &Mat, alpha: f64, src2: &Mat, dst: &mut Mat) -> Result<(), Error> {
+    ::cv_result(unsafe { ffi::Mat_ScaleAdd_Checked(src1.inner, alpha, src2.inner, dst.inner) })
+}
+
+/// Raises every array element to a power. For non-integer powers the
+/// absolute values of the elements are used.
+///
+/// OpenCV takes no mask for `pow`; every element is written.
+pub fn pow(src: &Mat, power: f64, dst: &mut Mat) -> Result<(), Error> {
+    ::cv_result(unsafe { ffi::Mat_Pow_Checked(src.inner, power, dst.inner) })
+}
+
+/// Calculates the square root of every element of a floating-point array.
+/// There is no masked variant in OpenCV.
+pub fn sqrt(src: &Mat, dst: &mut Mat) -> Result<(), Error> {
+    ::cv_result(unsafe { ffi::Mat_Sqrt_Checked(src.inner, dst.inner) })
+}
+
+/// Calculates the exponent of every element of a floating-point array.
+/// There is no masked variant in OpenCV.
+pub fn exp(src: &Mat, dst: &mut Mat) -> Result<(), Error> {
+    ::cv_result(unsafe { ffi::Mat_Exp_Checked(src.inner, dst.inner) })
+}
+
+/// Calculates the natural logarithm of every element of a floating-point
+/// array. There is no masked variant in OpenCV.
+pub fn log(src: &Mat, dst: &mut Mat) -> Result<(), Error> {
+    ::cv_result(unsafe { ffi::Mat_Log_Checked(src.inner, dst.inner) })
+}
+
+/// Calculates the magnitude of 2D vectors.
+pub fn magnitude(x: &Mat, y: &Mat, magnitude: &mut Mat) -> Result<(), Error> {
+    ::cv_result(unsafe { ffi::Mat_Magnitude_Checked(x.inner, y.inner, magnitude.inner) })
+}
+
+/// Calculates the rotation angle of 2D vectors.
+pub fn phase(x: &Mat, y: &Mat, angle: &mut Mat, use_degree: bool) -> Result<(), Error> {
+    ::cv_result(unsafe { ffi::Mat_Phase_Checked(x.inner, y.inner, angle.inner, use_degree) })
+}
+
+/// Calculates x and y coordinates of 2D vectors from their magnitude and
+/// angle, the inverse of [cart_to_polar](fn.cart_to_polar.html).
+pub fn polar_to_cart(
+    magnitude: &Mat,
+    angle: &Mat,
+    x: &mut Mat,
+    y: &mut Mat,
+    use_degree: bool,
+) -> Result<(), Error> {
+    ::cv_result(unsafe {
+        ffi::Mat_PolarToCart_Checked(magnitude.inner, angle.inner, x.inner, y.inner, use_degree)
+    })
+}
+
+/// Calculates the per-element minimum of two arrays. OpenCV takes neither a
+/// mask nor an output depth, so both inputs must have the same type.
+pub fn min(src1: &Mat, src2: &Mat, dst: &mut Mat) -> Result<(), Error> {
+    ::cv_result(unsafe { ffi::Mat_Min_Checked(src1.inner, src2.inner, dst.inner) })
+}
+
+/// Calculates the per-element maximum of two arrays. OpenCV takes neither a
+/// mask nor an output depth, so both inputs must have the same type.
+pub fn max(src1: &Mat, src2: &Mat, dst: &mut Mat) -> Result<(), Error> {
+    ::cv_result(unsafe { ffi::Mat_Max_Checked(src1.inner, src2.inner, dst.inner) })
+}
+
+/// Calculates the absolute value of every array element. OpenCV has no
+/// masked variant; copy the result with `Mat::copy_to_with_mask` if only some
+/// elements should change.
+pub fn abs(src: &Mat, dst: &mut Mat) -> Result<(), Error> {
+    ::cv_result(unsafe { ffi::Mat_Abs_Checked(src.inner, dst.inner) })
+}
+
+bitflags! {
+    /// Operation flags for the discrete Fourier and cosine transforms.
+    pub struct DftFlags: i32 {
+        /// Performs an inverse transform instead of the forward one.
+        const INVERSE = 1;
+        /// Scales the result by the number of array elements, usually
+        /// combined with `INVERSE` (DFT only).
+        const SCALE = 2;
+        /// Transforms every row of the input separately.
+        const ROWS = 4;
+        /// Produces a full-size complex output for a real input (DFT only).
+        const COMPLEX_OUTPUT = 16;
+        /// Produces a real output for a complex input with conjugate
+        /// symmetry, usually the result of an inverse transform (DFT only).
+        const REAL_OUTPUT = 32;
+    }
+}
+
+/// Performs a forward or inverse discrete Fourier transform of a 1D or 2D
+/// floating-point array.
+///
+/// Complex arrays have 2 channels. By default a real input results in a
+/// packed real output (CCS format); use `DftFlags::COMPLEX_OUTPUT` to get a
+/// 2-channel complex output. Only the first `nonzero_rows` rows of the input
+/// (or of the output for inverse transforms) are processed if it is not 0.
+pub fn dft(src: &Mat, dst: &mut Mat, flags: DftFlags, nonzero_rows: i32) -> Result<(), Error> {
+    ::cv_result(unsafe { ffi::Mat_DFT_Checked(src.inner, dst.inner, flags.bits(), nonzero_rows) })
+}
+
+/// Calculates the inverse discrete Fourier transform of a 1D or 2D array,
+/// same as [dft](fn.dft.html) with `DftFlags::INVERSE`. The result is not
+/// scaled unless `DftFlags::SCALE` is given.
+pub fn idft(src: &Mat, dst: &mut Mat, flags: DftFlags, nonzero_rows: i32) -> Result<(), Error> {
+    ::cv_result(unsafe { ffi::Mat_IDFT_Checked(src.inner, dst.inner, flags.bits(), nonzero_rows) })
+}
+
+/// Performs a forward or inverse discrete cosine transform of a 1D or 2D
+/// floating-point array of even size. Only `DftFlags::INVERSE` and
+/// `DftFlags::ROWS` apply.
+pub fn dct(src: &Mat, dst: &mut Mat, flags: DftFlags) -> Result<(), Error> {
+    ::cv_result(unsafe { ffi::Mat_DCT_Checked(src.inner, dst.inner, flags.bits()) })
+}
+
+/// Calculates the inverse discrete cosine transform of a 1D or 2D array,
+/// same as [dct](fn.dct.html) with `DftFlags::INVERSE`.
+pub fn idct(src: &Mat, dst: &mut Mat, flags: DftFlags) -> Result<(), Error> {
+    ::cv_result(unsafe { ffi::Mat_IDCT_Checked(src.inner, dst.inner, flags.bits()) })
+}
+
+/// Performs the per-element multiplication of two Fourier spectrums, both
+/// either packed real (CCS) or complex as produced by [dft](fn.dft.html).
+///
+/// Only `DftFlags::ROWS` applies. With `conj_b` the second spectrum is
+/// conjugated before the multiplication, which gives the cross-correlation
+/// used e.g. for phase correlation.
+pub fn mul_spectrums(
+    a: &Mat,
+    b: &Mat,
+    dst: &mut Mat,
+    flags: DftFlags,
+    conj_b: bool,
+) -> Result<(), Error> {
+    ::cv_result(unsafe {
+        ffi::Mat_MulSpectrums_Checked(a.inner, b.inner, dst.inner, flags.bits(), conj_b)
+    })
+}
+
+/// Returns the smallest size greater than or equal to `vecsize` for which
+/// the DFT can be computed efficiently, i.e. a product of 2, 3 and 5. Arrays
+/// can be padded to this size, e.g. with
+/// [copy_make_border](fn.copy_make_border.html).
+pub fn get_optimal_dft_size(vecsize: i32) -> i32 {
+    unsafe { ffi::GetOptimalDFTSize(vecsize) }
+}
+
+/// Splits a multi-channel array into single-channel arrays, one per channel.
+pub fn split(src: &Mat) -> Result<Vec<Mat>, Error> {
+    let channels: Vec<Mat> = (0..src.channels()).map(|_| Mat::new()).collect();
+    let mut handles: Vec<ffi::Mat> = channels.iter().map(|m| m.inner).collect();
+    ::cv_result(unsafe { ffi::Mat_Split_Checked(src.inner, to_mat_array(&mut handles)) })?;
+    Ok(channels)
+}
+
+/// Merges several arrays of the same size and depth into a multi-channel
+/// array, whose channels are the channels of `mats` in order.
+pub fn merge(mats: &[Mat]) -> Result<Mat, Error> {
+    let dst = Mat::new();
+    let mut handles: Vec<ffi::Mat> = mats.iter().map(|m| m.inner).collect();
+    ::cv_result(unsafe { ffi::Mat_Merge_Checked(to_mat_array(&mut handles), dst.inner) })?;
+    Ok(dst)
+}
+
+/// Copies the specified channels from the input arrays to the specified
+/// channels of the output arrays.
+///
+/// Each pair in `from_to` gives the index of an input channel and the index
+/// of the output channel it is copied to, where the channels of all arrays
+/// are numbered consecutively (the channels of `src[1]` start after those of
+/// `src[0]`, etc). An input index of -1 fills the output channel with zeros.
+/// The arrays in `dst` must have been allocated with the right size and
+/// depth.
+pub fn mix_channels(src: &[Mat], dst: &mut [Mat], from_to: &[(i32, i32)]) -> Result<(), Error> {
+    let mut src_handles: Vec<ffi::Mat> = src.iter().map(|m| m.inner).collect();
+    let mut dst_handles: Vec<ffi::Mat> = dst.iter().map(|m| m.inner).collect();
+    let pairs: Vec<i32> = from_to
+        .iter()
+        .flat_map(|&(from, to)| vec![from, to])
+        .collect();
+    ::cv_result(unsafe {
+        ffi::Mat_MixChannels_Checked(
+            to_mat_array(&mut src_handles),
+            to_mat_array(&mut dst_handles),
+            pairs.as_ptr(),
+            from_to.len() as i32,
+        )
+    })
+}
+
+/// Extracts the channel `coi` (0-based) of `src` into the single-channel
+/// array `dst`.
+pub fn extract_channel(src: &Mat, dst: &mut Mat, coi: i32) -> Result<(), Error> {
+    ::cv_result(unsafe { ffi::Mat_ExtractChannel_Checked(src.inner, dst.inner, coi) })
+}
+
+/// Replaces the channel `coi` (0-based) of `dst` with the single-channel
+/// array `src` of the same size and depth.
+pub fn insert_channel(src: &Mat, dst: &mut Mat, coi: i32) -> Result<(), Error> {
+    ::cv_result(unsafe { ffi::Mat_InsertChannel_Checked(src.inner, dst.inner, coi) })
+}
+
+/// Axis to flip an array around.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, FromPrimitive, ToPrimitive)]
+pub enum FlipCode {
+    /// Flips upside down, around the x-axis.
+    Vertical = 0,
+    /// Mirrors left to right, around the y-axis.
+    Horizontal = 1,
+    /// Flips around both axes.
+    Both = -1,
+}
+
+/// Flips an array vertically, horizontally or both.
+pub fn flip(src: &Mat, dst: &mut Mat, code: FlipCode) -> Result<(), Error> {
+    ::cv_result(unsafe { ffi::Mat_Flip_Checked(src.inner, dst.inner, code as i32) })
+}
+
+/// Clockwise rotation in multiples of 90 degrees.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, FromPrimitive, ToPrimitive)]
+pub enum RotateCode {
+    /// Rotates by 90 degrees clockwise.
+    Rotate90 = 0,
+    /// Rotates by 180 degrees.
+    Rotate180 = 1,
+    /// Rotates by 270 degrees clockwise, i.e. 90 degrees counterclockwise.
+    Rotate270 = 2,
+}
+
+/// Rotates an array by a multiple of 90 degrees.
+pub fn rotate(src: &Mat, dst: &mut Mat, code: RotateCode) -> Result<(), Error> {
+    ::cv_result(unsafe { ffi::Mat_Rotate_Checked(src.inner, dst.inner, code as i32) })
+}
+
+/// Fills `dst` with `ny` times `nx` copies of `src`, `ny` along the rows and
+/// `nx` along the columns.
+pub fn repeat(src: &Mat, ny: i32, nx: i32, dst: &mut Mat) -> Result<(), Error> {
+    ::cv_result(unsafe { ffi::Mat_Repeat_Checked(src.inner, ny, nx, dst.inner) })
+}
+
+/// Concatenates arrays with the same number of rows and the same type side
+/// by side.
+pub fn hconcat(mats: &[Mat]) -> Result<Mat, Error> {
+    let dst = Mat::new();
+    let mut handles: Vec<ffi::Mat> = mats.iter().map(|m| m.inner).collect();
+    ::cv_result(unsafe { ffi::Mat_Hconcat_Checked(to_mat_array(&mut handles), dst.inner) })?;
+    Ok(dst)
+}
+
+/// Concatenates arrays with the same number of columns and the same type on
+/// top of each other.
+pub fn vconcat(mats: &[Mat]) -> Result<Mat, Error> {
+    let dst = Mat::new();
+    let mut handles: Vec<ffi::Mat> = mats.iter().map(|m| m.inner).collect();
+    ::cv_result(unsafe { ffi::Mat_Vconcat_Checked(to_mat_array(&mut handles), dst.inner) })?;
+    Ok(dst)
+}
+
+/// Calculates the mean and the standard deviation of the array elements
+/// selected by the optional 8-bit `mask`, independently for each channel.
+pub fn mean_std_dev(src: &Mat, mask: Option<&Mat>) -> Result<(Scalar, Scalar), Error> {
+    let mut mean = Scalar {
+        val1: 0.0,
+        val2: 0.0,
+        val3: 0.0,
+        val4: 0.0,
+    };
+    let mut stddev = mean;
+    ::cv_result(unsafe {
+        ffi::Mat_MeanStdDev_Checked(src.inner, optional_mat(mask), &mut mean, &mut stddev)
+    })?;
+    Ok((mean, stddev))
+}
+
+/// Finds the minimum and maximum among the elements of a single-channel
+/// array selected by the 8-bit `mask`, and their locations.
+pub fn min_max_loc_with_mask(input: &Mat, mask: &Mat) -> Result<(f64, f64, Point, Point), Error> {
     let mut min = 0.0;
     let mut max = 0.0;
     let mut min_loc = Point { x: 0, y: 0 };
     let mut max_loc = Point { x: 0, y: 0 };
-    unsafe { ffi::Mat_MinMaxLoc(input.inner, &mut min, &mut max, &mut min_loc, &mut max_loc) }
-    (min, max, min_loc, max_loc)
+    ::cv_result(unsafe {
+        ffi::Mat_MinMaxLocWithMask_Checked(
+            input.inner,
+            mask.inner,
+            &mut min,
+            &mut max,
+            &mut min_loc,
+            &mut max_loc,
+        )
+    })?;
+    Ok((min, max, min_loc, max_loc))
+}
+
+/// Finds the minimum and maximum among the elements of an array selected by
+/// the optional 8-bit `mask`, and their indices, one per dimension (at least
+/// 2, e.g. row and column).
+///
+/// The minimum and maximum of a multi-channel array are taken over all of
+/// its channels, which does not support a mask and returns no indices.
+pub fn min_max_idx(src: &Mat, mask: Option<&Mat>) -> Result<(f64, f64, Vec<i32>, Vec<i32>), Error> {
+    let mut min = 0.0;
+    let mut max = 0.0;
+    let len = if src.channels() == 1 {
+        src.dims().max(2) as usize
+    } else {
+        0
+    };
+    let mut min_idx = vec![0; len];
+    let mut max_idx = vec![0; len];
+    let idx_ptr = |idx: &mut Vec<i32>| {
+        if idx.is_empty() {
+            ptr::null_mut()
+        } else {
+            idx.as_mut_ptr()
+        }
+    };
+    ::cv_result(unsafe {
+        ffi::Mat_MinMaxIdx_Checked(
+            src.inner,
+            optional_mat(mask),
+            &mut min,
+            &mut max,
+            idx_ptr(&mut min_idx),
+            idx_ptr(&mut max_idx),
+        )
+    })?;
+    Ok((min, max, min_idx, max_idx))
+}
+
+/// [Norm types](https://docs.opencv.org/master/d2/de8/group__core__array.html#gad12cefbcb5291cf958a85b4b67b6149f).
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, FromPrimitive, ToPrimitive)]
+pub enum NormType {
+    /// Maximum absolute value.
+    Inf = 1,
+    /// Sum of absolute values.
+    L1 = 2,
+    /// Euclidean norm, the square root of the sum of squares.
+    L2 = 4,
+    /// Sum of squares.
+    L2Sqr = 5,
+    /// Number of differing bits, for 8-bit arrays.
+    Hamming = 6,
+    /// Like `Hamming`, but counts pairs of bits, as used by ORB descriptors
+    /// with `WTA_K` 3 or 4.
+    Hamming2 = 7,
+}
+
+/// Calculates the norm of the array elements selected by the optional 8-bit
+/// `mask`.
+pub fn norm(src: &Mat, norm_type: NormType, mask: Option<&Mat>) -> Result<f64, Error> {
+    let mut result = 0.0;
+    ::cv_result(unsafe {
+        ffi::Mat_Norm_Checked(src.inner, norm_type as i32, optional_mat(mask), &mut result)
+    })?;
+    Ok(result)
+}
+
+/// Calculates the norm of the difference between two arrays, over the
+/// elements selected by the optional 8-bit `mask`.
+///
+/// With `relative` the result is divided by the norm of `src2`, i.e. it is
+/// the relative difference norm(src1 - src2) / norm(src2).
+pub fn norm_diff(
+    src1: &Mat,
+    src2: &Mat,
+    norm_type: NormType,
+    relative: bool,
+    mask: Option<&Mat>,
+) -> Result<f64, Error> {
+    let flags = if relative {
+        norm_type as i32 | NORM_RELATIVE
+    } else {
+        norm_type as i32
+    };
+    let mut result = 0.0;
+    ::cv_result(unsafe {
+        ffi::Mat_NormDiff_Checked(
+            src1.inner,
+            src2.inner,
+            flags,
+            optional_mat(mask),
+            &mut result,
+        )
+    })?;
+    Ok(result)
+}
+
+/// `cv::NORM_RELATIVE`, combined with a norm type.
+const NORM_RELATIVE: i32 = 8;
+
+/// Checks whether the array elements lie between the elements of `lower`
+/// and `upper` (inclusive), per channel.
+///
+/// `dst` is set to a `Cv8UC1` Mat of the size of `src` with 255 where all
+/// channels of an element are in range and 0 elsewhere.
+pub fn in_range(src: &Mat, lower: Scalar, upper: Scalar, dst: &mut Mat) -> Result<(), Error> {
+    ::cv_result(unsafe { ffi::Mat_InRange_Checked(src.inner, lower, upper, dst.inner) })
+}
+
+/// Normalization used by [normalize](fn.normalize.html).
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, FromPrimitive, ToPrimitive)]
+pub enum NormalizeType {
+    /// Scales so that the maximum absolute value is `alpha`.
+    Inf = 1,
+    /// Scales so that the sum of absolute values is `alpha`.
+    L1 = 2,
+    /// Scales so that the Euclidean norm is `alpha`.
+    L2 = 4,
+    /// Scales and shifts the values to the range `alpha` to `beta`.
+    MinMax = 32,
+}
+
+/// Normalizes the norm or the value range of the array elements selected by
+/// the optional 8-bit `mask`.
+///
+/// With `NormalizeType::MinMax` the values are scaled and shifted so that the
+/// minimum is `alpha` and the maximum `beta`; otherwise they are scaled so
+/// that their norm is `alpha` and `beta` is ignored. `dtype` is the depth of
+/// the output, by default the depth of `src`.
+pub fn normalize(
+    src: &Mat,
+    dst: &mut Mat,
+    alpha: f64,
+    beta: f64,
+    norm_type: NormalizeType,
+    dtype: Option<Depth>,
+    mask: Option<&Mat>,
+) -> Result<(), Error> {
+    ::cv_result(unsafe {
+        ffi::Mat_Normalize_Checked(
+            src.inner,
+            dst.inner,
+            alpha,
+            beta,
+            norm_type as i32,
+            optional_depth(dtype),
+            optional_mat(mask),
+        )
+    })
+}
+
+/// Dimension a matrix is reduced to by [reduce](fn.reduce.html).
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, FromPrimitive, ToPrimitive)]
+pub enum ReduceDimension {
+    /// Reduces all rows to a single row, i.e. each column to one value.
+    ToRow = 0,
+    /// Reduces all columns to a single column, i.e. each row to one value.
+    ToColumn = 1,
+}
+
+/// Operation used by [reduce](fn.reduce.html).
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, FromPrimitive, ToPrimitive)]
+pub enum ReduceType {
+    /// Sum of the values.
+    Sum = 0,
+    /// Mean of the values.
+    Avg = 1,
+    /// Maximum of the values.
+    Max = 2,
+    /// Minimum of the values.
+    Min = 3,
+}
+
+/// Reduces a matrix to a single row or column, independently for each
+/// channel.
+///
+/// `dtype` is the depth of the output, by default the depth of `src`. Sums
+/// and means of integer matrices usually need a wider depth, e.g. `Cv32S` or
+/// `Cv32F` for `Cv8U`.
+pub fn reduce(
+    src: &Mat,
+    dst: &mut Mat,
+    dim: ReduceDimension,
+    rtype: ReduceType,
+    dtype: Option<Depth>,
+) -> Result<(), Error> {
+    ::cv_result(unsafe {
+        ffi::Mat_Reduce_Checked(
+            src.inner,
+            dst.inner,
+            dim as i32,
+            rtype as i32,
+            optional_depth(dtype),
+        )
+    })
+}
+
+/// Random number generator, the multiply-with-carry generator of OpenCV.
+///
+/// Generators created with the same seed produce the same sequence of
+/// numbers, which makes noise in tests reproducible.
+#[derive(Debug)]
+pub struct Rng {
+    inner: ffi::RNG,
+}
+
+impl Drop for Rng {
+    fn drop(&mut self) {
+        unsafe { ffi::RNG_Close(self.inner) }
+    }
+}
+
+impl Rng {
+    /// Creates a generator with the given initial state. OpenCV replaces a
+    /// seed of 0 by a fixed non-zero state.
+    pub fn new(seed: u64) -> Rng {
+        Rng {
+            inner: unsafe { ffi::RNG_New(seed) },
+        }
+    }
+
+    /// Returns the next random number, uniformly distributed over all `u32`
+    /// values.
+    pub fn next_u32(&mut self) -> u32 {
+        unsafe { ffi::RNG_Next(self.inner) }
+    }
+
+    /// Returns a uniformly distributed integer in `[low, high)`.
+    pub fn uniform_i32(&mut self, low: i32, high: i32) -> i32 {
+        unsafe { ffi::RNG_UniformInt(self.inner, low, high) }
+    }
+
+    /// Returns a uniformly distributed number in `[low, high)`.
+    pub fn uniform_f64(&mut self, low: f64, high: f64) -> f64 {
+        unsafe { ffi::RNG_UniformDouble(self.inner, low, high) }
+    }
+
+    /// Returns a normally distributed number with mean 0 and standard
+    /// deviation `sigma`.
+    pub fn gaussian(&mut self, sigma: f64) -> f64 {
+        unsafe { ffi::RNG_Gaussian(self.inner, sigma) }
+    }
+
+    /// Fills `mat` with uniformly distributed values in `[low, high)`, per
+    /// channel. The Mat keeps its size and type, so it has to be allocated.
+    pub fn fill_uniform(&mut self, mat: &mut Mat, low: Scalar, high: Scalar) -> Result<(), Error> {
+        ::cv_result(unsafe { ffi::RNG_Fill(self.inner, mat.inner, 0, low, high) })
+    }
+
+    /// Fills `mat` with normally distributed values, per channel. Values are
+    /// saturated to the range of the Mat type.
+    pub fn fill_normal(
+        &mut self,
+        mat: &mut Mat,
+        mean: Scalar,
+        stddev: Scalar,
+    ) -> Result<(), Error> {
+        ::cv_result(unsafe { ffi::RNG_Fill(self.inner, mat.inner, 1, mean, stddev) })
+    }
+}
+
+impl Mat {
+    /// Fills this Mat with uniformly distributed values in `[low, high)`,
+    /// per channel, drawn from the default generator of the calling thread.
+    /// The Mat keeps its size and type, so it has to be allocated.
+    pub fn randu(&mut self, low: Scalar, high: Scalar) -> Result<(), Error> {
+        ::cv_result(unsafe { ffi::Mat_RandU_Checked(self.inner, low, high) })
+    }
+
+    /// Fills this Mat with normally distributed values, per channel, drawn
+    /// from the default generator of the calling thread. Values are
+    /// saturated to the range of the Mat type.
+    pub fn randn(&mut self, mean: Scalar, stddev: Scalar) -> Result<(), Error> {
+        ::cv_result(unsafe { ffi::Mat_RandN_Checked(self.inner, mean, stddev) })
+    }
+
+    /// Shuffles the elements of this Mat by swapping `iter_factor * total()`
+    /// random pairs. Uses the default generator of the calling thread if
+    /// `rng` is `None`.
+    pub fn rand_shuffle(&mut self, iter_factor: f64, rng: Option<&mut Rng>) -> Result<(), Error> {
+        let rng = rng.map_or(ptr::null_mut(), |r| r.inner);
+        ::cv_result(unsafe { ffi::Mat_RandShuffle_Checked(self.inner, iter_factor, rng) })
+    }
+}
+
+/// Seeds the default random number generator of the calling thread, used by
+/// [Mat::randu](struct.Mat.html#method.randu) and friends.
+pub fn set_rng_seed(seed: i32) {
+    unsafe { ffi::SetRNGSeed(seed) }
+}
+
+/// Initialization of the cluster centers in [kmeans](fn.kmeans.html).
+#[derive(Debug, Clone, Copy)]
+pub enum KmeansFlags<'a> {
+    /// Picks random initial centers in each attempt.
+    RandomCenters,
+    /// Uses the kmeans++ center initialization by Arthur and Vassilvitskii.
+    PpCenters,
+    /// Starts the first attempt from the given labels, a `Cv32SC1` column
+    /// with one cluster index per sample. Further attempts pick random
+    /// initial centers.
+    UseInitialLabels(&'a Mat),
+}
+
+/// Finds the centers of `k` clusters and groups the samples around them.
+///
+/// `data` holds one sample per row and has to be of depth `Cv32F`, e.g. a
+/// N×2 `Cv32FC1` Mat of point coordinates or a N×1 `Cv32FC3` Mat of colors.
+/// The algorithm runs `attempts` times and keeps the labeling with the
+/// lowest compactness.
+///
+/// Returns the compactness, i.e. the sum of squared distances of the samples
+/// to their centers, the label of each sample as a `Cv32SC1` column and the
+/// centers with one row per cluster.
+pub fn kmeans(
+    data: &Mat,
+    k: i32,
+    criteria: TermCriteria,
+    attempts: i32,
+    flags: KmeansFlags,
+) -> Result<(f64, Mat, Mat), Error> {
+    let (labels, flags) = match flags {
+        KmeansFlags::RandomCenters => (Mat::new(), 0),
+        KmeansFlags::PpCenters => (Mat::new(), 2),
+        KmeansFlags::UseInitialLabels(labels) => (labels.clone(), 1),
+    };
+    let centers = Mat::new();
+    let mut compactness = 0.0;
+    ::cv_result(unsafe {
+        ffi::Mat_KMeans_Checked(
+            data.inner,
+            k,
+            labels.inner,
+            criteria.into(),
+            attempts,
+            flags,
+            centers.inner,
+            &mut compactness,
+        )
+    })?;
+    Ok((compactness, labels, centers))
+}
+
+/// Passes an optional Mat argument to the shims, which take NULL for none.
+pub(crate) fn optional_mat(m: Option<&Mat>) -> ffi::Mat {
+    m.map_or(ptr::null_mut(), |m| m.inner)
+}
+
+/// Passes an optional output depth to OpenCV, which takes -1 for the depth
+/// of the input.
+pub(crate) fn optional_depth(depth: Option<Depth>) -> i32 {
+    depth.map_or(-1, |d| d as i32)
+}
+
+pub(crate) fn to_points2f(points: &mut [Point2f]) -> ffi::Points2f {
+    ffi::Points2f {
+        points: points.as_mut_ptr(),
+        length: points.len() as i32,
+    }
+}
+
+fn to_mat_array(mats: &mut [ffi::Mat]) -> ffi::MatArray {
+    ffi::MatArray {
+        mats: mats.as_mut_ptr(),
+        length: mats.len() as i32,
+    }
 }
 
 fn to_byte_array(buf: &mut [i8]) -> ffi::ByteArray {
@@ -0,0 +1,243 @@
+//! [Linear algebra][opencv-linalg] on floating-point matrices.
+//!
+//! [opencv-linalg]: https://docs.opencv.org/master/d2/de8/group__core__array.html
+use core::{optional_depth, optional_mat, Depth, Mat, Scalar};
+use opencv_sys as ffi;
+use Error;
+
+bitflags! {
+    /// Operation flags for [gemm](fn.gemm.html).
+    pub struct GemmFlags: i32 {
+        /// Transposes `src1`.
+        const TRANSPOSE_SRC1 = 1;
+        /// Transposes `src2`.
+        const TRANSPOSE_SRC2 = 2;
+        /// Transposes `src3`.
+        const TRANSPOSE_SRC3 = 4;
+    }
+}
+
+/// Performs a generalized matrix multiplication (dst = alpha \* src1 \* src2 +
+/// beta \* src3), where each operand may be transposed according to `flags`.
+/// Without `src3` only the product is computed.
+pub fn gemm(
+    src1: &Mat,
+    src2: &Mat,
+    alpha: f64,
+    src3: Option<&Mat>,
+    beta: f64,
+    dst: &mut Mat,
+    flags: GemmFlags,
+) -> Result<(), Error> {
+    ::cv_result(unsafe {
+        ffi::Mat_Gemm_Checked(
+            src1.inner,
+            src2.inner,
+            alpha,
+            optional_mat(src3),
+            beta,
+            dst.inner,
+            flags.bits(),
+        )
+    })
+}
+
+/// Transposes a matrix.
+pub fn transpose(src: &Mat, dst: &mut Mat) -> Result<(), Error> {
+    ::cv_result(unsafe { ffi::Mat_Transpose_Checked(src.inner, dst.inner) })
+}
+
+/// Matrix decomposition methods used to invert matrices and to solve linear
+/// systems.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, FromPrimitive, ToPrimitive)]
+pub enum DecompType {
+    /// Gaussian elimination with the optimal pivot element chosen.
+    Lu = 0,
+    /// Singular value decomposition; the system can be over-defined and/or
+    /// the matrix singular.
+    Svd = 1,
+    /// Eigenvalue decomposition; the matrix must be symmetrical.
+    Eig = 2,
+    /// Cholesky factorization; the matrix must be symmetrical and positively
+    /// defined.
+    Cholesky = 3,
+    /// QR factorization; the system can be over-defined and/or the matrix
+    /// singular.
+    Qr = 4,
+}
+
+/// Finds the inverse or pseudo-inverse of a matrix.
+///
+/// Returns 0 if `src` is singular for `Lu` and `Cholesky` (`dst` is then
+/// filled with zeros), and the inverse condition number of `src` for `Svd`.
+pub fn invert(src: &Mat, dst: &mut Mat, method: DecompType) -> Result<f64, Error> {
+    let mut result = 0.0;
+    ::cv_result(unsafe {
+        ffi::Mat_Invert_Checked(src.inner, dst.inner, method as i32, &mut result)
+    })?;
+    Ok(result)
+}
+
+/// Solves the linear system src1 \* dst = src2, or the least-squares problem
+/// for over-defined systems with `Svd` or `Qr`.
+///
+/// Returns false if `src1` is singular for `Lu` and `Cholesky`.
+pub fn solve(src1: &Mat, src2: &Mat, dst: &mut Mat, method: DecompType) -> Result<bool, Error> {
+    let mut result = false;
+    ::cv_result(unsafe {
+        ffi::Mat_Solve_Checked(
+            src1.inner,
+            src2.inner,
+            dst.inner,
+            method as i32,
+            &mut result,
+        )
+    })?;
+    Ok(result)
+}
+
+/// Returns the determinant of a square floating-point matrix.
+pub fn determinant(m: &Mat) -> Result<f64, Error> {
+    let mut result = 0.0;
+    ::cv_result(unsafe { ffi::Mat_Determinant_Checked(m.inner, &mut result) })?;
+    Ok(result)
+}
+
+/// Returns the sum of the diagonal elements of a matrix, per channel.
+pub fn trace(m: &Mat) -> Result<Scalar, Error> {
+    let mut result = Scalar {
+        val1: 0.0,
+        val2: 0.0,
+        val3: 0.0,
+        val4: 0.0,
+    };
+    ::cv_result(unsafe { ffi::Mat_Trace_Checked(m.inner, &mut result) })?;
+    Ok(result)
+}
+
+bitflags! {
+    /// Operation flags for [Svd::compute](struct.Svd.html#method.compute).
+    pub struct SvdFlags: i32 {
+        /// Only computes the singular values; `u` and `vt` are left empty.
+        const NO_UV = 2;
+        /// Computes full-size square `u` and `vt` for a non-square matrix.
+        const FULL_UV = 4;
+    }
+}
+
+/// Singular value decomposition of a floating-point matrix, src = u \*
+/// diag(w) \* vt.
+#[derive(Debug)]
+pub struct Svd {
+    w: Mat,
+    u: Mat,
+    vt: Mat,
+}
+
+impl Svd {
+    /// Decomposes `src`.
+    pub fn compute(src: &Mat, flags: SvdFlags) -> Result<Svd, Error> {
+        let svd = Svd {
+            w: Mat::new(),
+            u: Mat::new(),
+            vt: Mat::new(),
+        };
+        ::cv_result(unsafe {
+            ffi::SVD_Compute_Checked(
+                src.inner,
+                svd.w.inner,
+                svd.u.inner,
+                svd.vt.inner,
+                flags.bits(),
+            )
+        })?;
+        Ok(svd)
+    }
+
+    /// Returns the singular values as a column vector, in descending order.
+    pub fn w(&self) -> &Mat {
+        &self.w
+    }
+
+    /// Returns the left singular vectors.
+    pub fn u(&self) -> &Mat {
+        &self.u
+    }
+
+    /// Returns the transposed right singular vectors.
+    pub fn vt(&self) -> &Mat {
+        &self.vt
+    }
+
+    /// Performs a back substitution: solves src \* dst = rhs for the
+    /// decomposed matrix, in the least-squares sense for over-defined
+    /// systems.
+    pub fn back_subst(&self, rhs: &Mat, dst: &mut Mat) -> Result<(), Error> {
+        ::cv_result(unsafe {
+            ffi::SVD_BackSubst_Checked(
+                self.w.inner,
+                self.u.inner,
+                self.vt.inner,
+                rhs.inner,
+                dst.inner,
+            )
+        })
+    }
+}
+
+/// Calculates the eigenvalues and eigenvectors of a symmetric matrix.
+///
+/// The eigenvalues are stored in descending order, the eigenvectors as rows
+/// of `eigenvectors` in the same order.
+pub fn eigen(src: &Mat, eigenvalues: &mut Mat, eigenvectors: &mut Mat) -> Result<bool, Error> {
+    let mut result = false;
+    ::cv_result(unsafe {
+        ffi::Mat_Eigen_Checked(
+            src.inner,
+            eigenvalues.inner,
+            eigenvectors.inner,
+            &mut result,
+        )
+    })?;
+    Ok(result)
+}
+
+/// Calculates the product of a matrix and its transposition, dst = scale \*
+/// (src - delta)<sup>T</sup> \* (src - delta) if `a_ta` is true, and dst =
+/// scale \* (src - delta) \* (src - delta)<sup>T</sup> otherwise.
+///
+/// `dtype` is the depth of the output, by default the depth of `src` but at
+/// least `Cv32F`.
+pub fn mul_transposed(
+    src: &Mat,
+    dst: &mut Mat,
+    a_ta: bool,
+    delta: Option<&Mat>,
+    scale: f64,
+    dtype: Option<Depth>,
+) -> Result<(), Error> {
+    ::cv_result(unsafe {
+        ffi::Mat_MulTransposed_Checked(
+            src.inner,
+            dst.inner,
+            a_ta,
+            optional_mat(delta),
+            scale,
+            optional_depth(dtype),
+        )
+    })
+}
+
+/// Computes the cross-product of two 3-element vectors.
+pub fn cross(a: &Mat, b: &Mat, dst: &mut Mat) -> Result<(), Error> {
+    ::cv_result(unsafe { ffi::Mat_Cross_Checked(a.inner, b.inner, dst.inner) })
+}
+
+/// Computes the dot-product of two Mats of the same size and type, treating
+/// them as vectors. Multi-channel Mats sum the products of all channels.
+pub fn dot(a: &Mat, b: &Mat) -> Result<f64, Error> {
+    let mut result = 0.0;
+    ::cv_result(unsafe { ffi::Mat_Dot_Checked(a.inner, b.inner, &mut result) })?;
+    Ok(result)
+}
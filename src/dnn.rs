@@ -3,6 +3,7 @@
 use opencv_sys as ffi;
 use core::{Mat, Scalar, Size};
 use std::ffi::CString;
+use std::ptr;
 use Error;
 
 /// Cascade classifier class for object detection.
@@ -17,17 +18,19 @@ impl Net {
         let prototxt = CString::new(prototxt)?;
         let model = CString::new(model)?;
 
-        Ok(Net {
-            inner: unsafe { ffi::Net_ReadNetFromCaffe(prototxt.as_ptr(), model.as_ptr()) },
-        })
+        let mut inner = ptr::null_mut();
+        ::cv_result(unsafe {
+            ffi::Net_ReadNetFromCaffe_Checked(prototxt.as_ptr(), model.as_ptr(), &mut inner)
+        })?;
+        Ok(Net { inner })
     }
 
     /// Reads a network model stored in Caffe framework's format.
     pub fn from_tensorflow(model: &str) -> Result<Self, Error> {
         let model = CString::new(model)?;
-        Ok(Net {
-            inner: unsafe { ffi::Net_ReadNetFromTensorflow(model.as_ptr()) },
-        })
+        let mut inner = ptr::null_mut();
+        ::cv_result(unsafe { ffi::Net_ReadNetFromTensorflow_Checked(model.as_ptr(), &mut inner) })?;
+        Ok(Net { inner })
     }
 
     /// Returns true if there are no layers in the network.
@@ -38,16 +41,18 @@ impl Net {
     /// Sets the new value for the layer output blob.
     pub fn set_input(&mut self, blob: &Mat, name: &str) -> Result<(), Error> {
         let name = CString::new(name)?;
-        unsafe { ffi::Net_SetInput(self.inner, blob.inner, name.as_ptr()) };
-        Ok(())
+        ::cv_result(unsafe { ffi::Net_SetInput_Checked(self.inner, blob.inner, name.as_ptr()) })
     }
 
-    /// Runs forward pass to compute output of layer with name outputName.
+    /// Runs forward pass to compute output of layer with name outputName. The
+    /// output is a copy, so it is not changed by later passes.
     pub fn forward(&self, output_name: &str) -> Result<Mat, Error> {
         let output_name = CString::new(output_name)?;
-        Ok(Mat::from(unsafe {
-            ffi::Net_Forward(self.inner, output_name.as_ptr())
-        }))
+        let mut result = ptr::null_mut();
+        ::cv_result(unsafe {
+            ffi::Net_Forward_Checked(self.inner, output_name.as_ptr(), &mut result)
+        })?;
+        Ok(Mat::from(result))
     }
 }
 
@@ -61,8 +66,12 @@ pub fn blob_from_image(
     mean: Scalar,
     swap_rb: bool,
     crop: bool,
-) -> Mat {
-    Mat::from(unsafe { ffi::Net_BlobFromImage(img.inner, scale, size, mean, swap_rb, crop) })
+) -> Result<Mat, Error> {
+    let mut result = ptr::null_mut();
+    ::cv_result(unsafe {
+        ffi::Net_BlobFromImage_Checked(img.inner, scale, size, mean, swap_rb, crop, &mut result)
+    })?;
+    Ok(Mat::from(result))
 }
 
 impl Drop for Net {
@@ -74,11 +83,23 @@ impl Drop for Net {
 /// Extracts a single (2d)channel from a 4 dimensional blob structure (this
 ///  might e.g. contain the results of a SSD or YOLO detection, a bones
 ///  structure from pose detection, or a color plane from Colorization)
-pub fn get_blob_channel(blob: &Mat, image_index: i32, channel_index: i32) -> Mat {
-    Mat::from(unsafe { ffi::Net_GetBlobChannel(blob.inner, image_index, channel_index) })
+///  into a new Mat.
+pub fn get_blob_channel(blob: &Mat, image_index: i32, channel_index: i32) -> Result<Mat, Error> {
+    let mut result = ptr::null_mut();
+    ::cv_result(unsafe {
+        ffi::Net_GetBlobChannel_Checked(blob.inner, image_index, channel_index, &mut result)
+    })?;
+    Ok(Mat::from(result))
 }
 
 /// Retrieves the 4 dimensional size information in (N,C,H,W) order
-pub fn get_blob_size(blob: &Mat) -> Scalar {
-    unsafe { ffi::Net_GetBlobSize(blob.inner) }
+pub fn get_blob_size(blob: &Mat) -> Result<Scalar, Error> {
+    let mut result = Scalar {
+        val1: 0.0,
+        val2: 0.0,
+        val3: 0.0,
+        val4: 0.0,
+    };
+    ::cv_result(unsafe { ffi::Net_GetBlobSize_Checked(blob.inner, &mut result) })?;
+    Ok(result)
 }
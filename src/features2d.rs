@@ -4,6 +4,8 @@
 
 use opencv_sys as ffi;
 use core::*;
+use std::ptr;
+use Error;
 
 /// Maximally stable extremal region extractor.
 #[derive(Debug)]
@@ -26,9 +28,10 @@ impl MSER {
     }
 
     /// Detects keypoints in an image using MSER.
-    pub fn detect(&self, src: &Mat) -> Vec<KeyPoint> {
-        let keypoints = unsafe { ffi::MSER_Detect(self.inner, src.inner) };
-        get_keypoints(keypoints)
+    pub fn detect(&self, src: &Mat) -> Result<Vec<KeyPoint>, Error> {
+        let mut keypoints = empty_keypoints();
+        ::cv_result(unsafe { ffi::MSER_Detect_Checked(self.inner, src.inner, &mut keypoints) })?;
+        Ok(get_keypoints(keypoints))
     }
 }
 
@@ -69,9 +72,19 @@ impl SimpleBlobDetector {
     }
 
     /// Detect keypoints in an image using SimpleBlobDetector.
-    pub fn detect(&self, src: &Mat) -> Vec<KeyPoint> {
-        let keypoints = unsafe { ffi::SimpleBlobDetector_Detect(self.inner, src.inner) };
-        get_keypoints(keypoints)
+    pub fn detect(&self, src: &Mat) -> Result<Vec<KeyPoint>, Error> {
+        let mut keypoints = empty_keypoints();
+        ::cv_result(unsafe {
+            ffi::SimpleBlobDetector_Detect_Checked(self.inner, src.inner, &mut keypoints)
+        })?;
+        Ok(get_keypoints(keypoints))
+    }
+}
+
+fn empty_keypoints() -> ffi::KeyPoints {
+    ffi::KeyPoints {
+        keypoints: ptr::null_mut(),
+        length: 0,
     }
 }
 
@@ -60,9 +60,7 @@ impl Window {
     /// Creates a new named window.
     pub fn new(name: &str, flag: WindowFlag) -> Result<Self, Error> {
         let s = CString::new(name)?;
-        unsafe {
-            ffi::Window_New(s.as_ptr(), flag as i32);
-        }
+        ::cv_result(unsafe { ffi::Window_New_Checked(s.as_ptr(), flag as i32) })?;
         Ok(Window {
             name: s,
             open: true,
@@ -82,46 +80,54 @@ impl Window {
     /// Displays an image in the specified window. This function should be
     /// followed by the `WaitKey` function which displays the image for
     /// specified milliseconds. Otherwise, it won't display the image.
-    pub fn show(&self, image: &Mat) {
-        unsafe { ffi::Window_IMShow(self.name.as_ptr(), image.inner) }
+    pub fn show(&self, image: &Mat) -> Result<(), Error> {
+        ::cv_result(unsafe { ffi::Window_IMShow_Checked(self.name.as_ptr(), image.inner) })
     }
 
     /// Waits for a pressed key. This function is the only method in OpenCV's
     /// HighGUI that can fetch and handle events, so it needs to be called
     /// periodically for normal event processing
-    pub fn wait_key(&self, delay: i32) -> i32 {
-        unsafe { ffi::Window_WaitKey(delay) }
+    pub fn wait_key(&self, delay: i32) -> Result<i32, Error> {
+        let mut key = 0;
+        ::cv_result(unsafe { ffi::Window_WaitKey_Checked(delay, &mut key) })?;
+        Ok(key)
     }
 
     /// Returns properties of a window.
-    pub fn get_property(&self, flag: WindowProperty) -> f64 {
-        unsafe { ffi::Window_GetProperty(self.name.as_ptr(), flag as i32) }
+    pub fn get_property(&self, flag: WindowProperty) -> Result<f64, Error> {
+        let mut value = 0.0;
+        ::cv_result(unsafe {
+            ffi::Window_GetProperty_Checked(self.name.as_ptr(), flag as i32, &mut value)
+        })?;
+        Ok(value)
     }
 
     /// Changes parameters of a window dynamically.
-    pub fn set_property(&mut self, flag: WindowProperty, value: WindowFlag) {
-        unsafe { ffi::Window_SetProperty(self.name.as_ptr(), flag as i32, (value as i32) as f64) }
+    pub fn set_property(&mut self, flag: WindowProperty, value: WindowFlag) -> Result<(), Error> {
+        ::cv_result(unsafe {
+            ffi::Window_SetProperty_Checked(self.name.as_ptr(), flag as i32, (value as i32) as f64)
+        })
     }
 
     /// Changes Window name dynamically.
     pub fn set_title(&mut self, title: &str) -> Result<(), Error> {
-        unsafe { ffi::Window_SetTitle(self.name.as_ptr(), CString::new(title)?.as_ptr()) }
-        Ok(())
+        let title = CString::new(title)?;
+        ::cv_result(unsafe { ffi::Window_SetTitle_Checked(self.name.as_ptr(), title.as_ptr()) })
     }
 
     /// Moves window to the specified position.
-    pub fn move_window(&mut self, x: i32, y: i32) {
-        unsafe { ffi::Window_Move(self.name.as_ptr(), x, y) }
+    pub fn move_window(&mut self, x: i32, y: i32) -> Result<(), Error> {
+        ::cv_result(unsafe { ffi::Window_Move_Checked(self.name.as_ptr(), x, y) })
     }
 
     /// Resizes window to the specified size.
-    pub fn resize(&mut self, width: i32, height: i32) {
-        unsafe { ffi::Window_Resize(self.name.as_ptr(), width, height) }
+    pub fn resize(&mut self, width: i32, height: i32) -> Result<(), Error> {
+        ::cv_result(unsafe { ffi::Window_Resize_Checked(self.name.as_ptr(), width, height) })
     }
 
     /// Closes the window.
-    pub fn close(&mut self) {
+    pub fn close(&mut self) -> Result<(), Error> {
         self.open = false;
-        unsafe { ffi::Window_Close(self.name.as_ptr()) }
+        ::cv_result(unsafe { ffi::Window_Close_Checked(self.name.as_ptr()) })
     }
 }
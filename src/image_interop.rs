@@ -0,0 +1,154 @@
+//! Conversions between [Mat](../core/struct.Mat.html) and
+//! [image](https://docs.rs/image) buffers, enabled by the `image` feature.
+
+use core::{CvType, Depth, Mat};
+use image::{ImageBuffer, Luma, Pixel, Rgb, Rgba};
+use std::mem::size_of;
+use std::slice;
+use {CvError, Error};
+
+/// Order of the color channels stored in a Mat.
+///
+/// OpenCV works with BGR(A) images (e.g. the result of `imread`), while the
+/// image crate always stores RGB(A). Grayscale images are not affected.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ChannelOrder {
+    /// The Mat stores blue, green, red (and alpha); the red and blue channels
+    /// are swapped during the conversion.
+    Bgr,
+    /// The Mat stores red, green, blue (and alpha) like the image buffer;
+    /// the channels are copied as they are.
+    Rgb,
+}
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// Pixel types of the image crate that can be converted to and from a Mat:
+/// `Luma<u8>`, `Rgb<u8>`, `Rgba<u8>`, `Luma<u16>` and `Rgb<f32>`.
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait ImagePixel: Pixel + private::Sealed + 'static {
+    /// Returns the type of a Mat holding pixels of this type.
+    fn cv_type() -> CvType;
+}
+
+macro_rules! impl_image_pixel {
+    ($($p:ident<$t:ty> => $depth:expr),*) => {
+        $(
+            impl private::Sealed for $p<$t> {}
+
+            impl ImagePixel for $p<$t> {
+                fn cv_type() -> CvType {
+                    CvType::new($depth, i32::from(<$p<$t> as Pixel>::channel_count()))
+                }
+            }
+        )*
+    };
+}
+
+impl_image_pixel!(
+    Luma<u8> => Depth::Cv8U,
+    Rgb<u8> => Depth::Cv8U,
+    Rgba<u8> => Depth::Cv8U,
+    Luma<u16> => Depth::Cv16U,
+    Rgb<f32> => Depth::Cv32F
+);
+
+impl Mat {
+    /// Copies an image buffer into a new Mat of the matching type, e.g.
+    /// `Cv8UC3` for an `RgbImage`. With `ChannelOrder::Bgr` the channels are
+    /// reordered so that the Mat can be passed to OpenCV functions expecting
+    /// BGR images. Fails if the image is too large for a Mat.
+    pub fn from_image<P>(
+        image: &ImageBuffer<P, Vec<P::Subpixel>>,
+        order: ChannelOrder,
+    ) -> Result<Mat, Error>
+    where
+        P: ImagePixel,
+        P::Subpixel: 'static,
+    {
+        let (width, height) = image.dimensions();
+        if width > i32::MAX as u32 || height > i32::MAX as u32 {
+            return Err(CvError::SizeTooLarge {
+                rows: height as usize,
+                cols: width as usize,
+            }
+            .into());
+        }
+        let mut mat = Mat::new_with_size(height as i32, width as i32, P::cv_type())?;
+        if image.is_empty() {
+            return Ok(mat);
+        }
+
+        let len = width as usize * usize::from(P::channel_count());
+        for (row, src) in image.chunks(len).enumerate() {
+            let bytes = mat.data_row_mut(row as i32).expect("freshly allocated Mat");
+            let dst =
+                unsafe { slice::from_raw_parts_mut(bytes.as_mut_ptr() as *mut P::Subpixel, len) };
+            dst.copy_from_slice(src);
+            swap_red_blue::<P>(dst, order);
+        }
+        Ok(mat)
+    }
+
+    /// Copies this Mat into a new image buffer. The Mat has to be a 2D Mat
+    /// of the type matching `P`, e.g. `Cv8UC3` for `Rgb<u8>`. With
+    /// `ChannelOrder::Bgr` the channels of the Mat are taken to be BGR(A) and
+    /// are reordered to RGB(A).
+    pub fn to_image<P>(
+        &self,
+        order: ChannelOrder,
+    ) -> Result<ImageBuffer<P, Vec<P::Subpixel>>, Error>
+    where
+        P: ImagePixel,
+        P::Subpixel: 'static,
+    {
+        let expected = P::cv_type();
+        let actual = self.cv_type();
+        if actual != expected {
+            return Err(CvError::TypeMismatch { expected, actual }.into());
+        }
+        let dims = self.dims();
+        if dims > 2 {
+            return Err(CvError::DimensionMismatch {
+                expected: 2,
+                actual: dims,
+            }
+            .into());
+        }
+
+        let (width, height) = if self.empty() {
+            (0, 0)
+        } else {
+            (self.cols() as u32, self.rows() as u32)
+        };
+        let len = width as usize * usize::from(P::channel_count());
+        let mut data = Vec::with_capacity(len * height as usize);
+        for row in 0..height {
+            let bytes = self.data_row(row as i32).expect("row in range");
+            let src = unsafe {
+                slice::from_raw_parts(
+                    bytes.as_ptr() as *const P::Subpixel,
+                    bytes.len() / size_of::<P::Subpixel>(),
+                )
+            };
+            data.extend_from_slice(src);
+        }
+        swap_red_blue::<P>(&mut data, order);
+        Ok(ImageBuffer::from_raw(width, height, data).expect("buffer matches the image size"))
+    }
+}
+
+/// Swaps the first and third channel of every pixel in `data` for
+/// `ChannelOrder::Bgr` color images.
+fn swap_red_blue<P: Pixel>(data: &mut [P::Subpixel], order: ChannelOrder) {
+    let channels = usize::from(P::channel_count());
+    if order == ChannelOrder::Rgb || channels < 3 {
+        return;
+    }
+    for pixel in data.chunks_mut(channels) {
+        pixel.swap(0, 2);
+    }
+}
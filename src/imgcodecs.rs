@@ -5,6 +5,7 @@
 use core::Mat;
 use opencv_sys as ffi;
 use std::path::Path;
+use std::ptr;
 use failure::Error;
 
 /// [ImageReadMode][opencv-imread].
@@ -70,7 +71,8 @@ pub enum ImageReadMode {
 pub fn imread<P: AsRef<Path>>(path: P, flags: ImageReadMode) -> Result<Mat, Error> {
     let path = ::path_to_cstring(path)?;
     let path = path.as_ptr();
-    let mat = unsafe { ffi::Image_IMRead(path, flags as i32) };
+    let mut mat = ptr::null_mut();
+    ::cv_result(unsafe { ffi::Image_IMRead_Checked(path, flags as i32, &mut mat) })?;
     Ok(mat.into())
 }
 
@@ -134,15 +136,18 @@ pub enum ImageWritePngFlag {
     Fixed = 4,
 }
 
-/// Writes an image to a file.
+/// Writes an image to a file. The format is chosen from the file extension;
+/// an unknown extension or an image the format cannot store is an error.
 pub fn imwrite<P: AsRef<Path>>(path: P, mat: &Mat) -> Result<bool, Error> {
     let path = ::path_to_cstring(path)?;
     let path = path.as_ptr();
-    let ret = unsafe { ffi::Image_IMWrite(path, mat.inner) };
+    let mut ret = false;
+    ::cv_result(unsafe { ffi::Image_IMWrite_Checked(path, mat.inner, &mut ret) })?;
     Ok(ret)
 }
 
-/// Writes an image to a file.
+/// Writes an image to a file, like [imwrite](fn.imwrite.html), with
+/// format-specific parameters.
 pub fn imwrite_with_params<P: AsRef<Path>>(
     path: P,
     mat: &Mat,
@@ -154,6 +159,9 @@ pub fn imwrite_with_params<P: AsRef<Path>>(
     };
     let path = ::path_to_cstring(path)?;
     let path = path.as_ptr();
-    let ret = unsafe { ffi::Image_IMWrite_WithParams(path, mat.inner, int_vector) };
+    let mut ret = false;
+    ::cv_result(unsafe {
+        ffi::Image_IMWrite_WithParams_Checked(path, mat.inner, int_vector, &mut ret)
+    })?;
     Ok(ret)
 }
@@ -2,6 +2,7 @@
 
 use opencv_sys as ffi;
 use core::{BorderType, Mat, Point, Rect, Scalar, Size};
+use Error;
 
 fn to_points(curve: &mut [Point]) -> ffi::Points {
     ffi::Points {
@@ -11,8 +12,10 @@ fn to_points(curve: &mut [Point]) -> ffi::Points {
 }
 
 /// Calculates a contour perimeter or a curve length.
-pub fn arc_length(curve: &mut Vec<Point>, is_closed: bool) -> f64 {
-    unsafe { ffi::ArcLength(to_points(curve), is_closed) }
+pub fn arc_length(curve: &mut Vec<Point>, is_closed: bool) -> Result<f64, Error> {
+    let mut result = 0.0;
+    ::cv_result(unsafe { ffi::ArcLength_Checked(to_points(curve), is_closed, &mut result) })?;
+    Ok(result)
 }
 
 /// Color conversion code used in `cvt_color`.
@@ -157,8 +160,8 @@ pub enum ColorConversion {
 }
 
 /// Convert an image from one color space to another.
-pub fn cvt_color(src: &Mat, dst: &mut Mat, code: ColorConversion) {
-    unsafe { ffi::CvtColor(src.inner, dst.inner, code as i32) }
+pub fn cvt_color(src: &Mat, dst: &mut Mat, code: ColorConversion) -> Result<(), Error> {
+    ::cv_result(unsafe { ffi::CvtColor_Checked(src.inner, dst.inner, code as i32) })
 }
 
 /// TemplateMatchMode is the type of the template matching operation.
@@ -181,20 +184,22 @@ pub fn match_template(
     dst: &mut Mat,
     mode: TemplateMatchMode,
     mask: &Mat,
-) {
-    unsafe { ffi::MatchTemplate(image.inner, templ.inner, dst.inner, mode as i32, mask.inner) }
+) -> Result<(), Error> {
+    ::cv_result(unsafe {
+        ffi::MatchTemplate_Checked(image.inner, templ.inner, dst.inner, mode as i32, mask.inner)
+    })
 }
 
 /// Blurs an image and downsamples it. This function performs the
 /// downsampling step of the Gaussian pyramid construction.
-pub fn pyr_down(src: &Mat, dst: &mut Mat, size: Size, border: BorderType) {
-    unsafe { ffi::PyrDown(src.inner, dst.inner, size, border as i32) }
+pub fn pyr_down(src: &Mat, dst: &mut Mat, size: Size, border: BorderType) -> Result<(), Error> {
+    ::cv_result(unsafe { ffi::PyrDown_Checked(src.inner, dst.inner, size, border as i32) })
 }
 
 /// Upsamples an image and then blurs it. This function performs the upsampling
 /// step of the Gaussian pyramid construction.
-pub fn pyr_up(src: &Mat, dst: &mut Mat, size: Size, border: BorderType) {
-    unsafe { ffi::PyrUp(src.inner, dst.inner, size, border as i32) }
+pub fn pyr_up(src: &Mat, dst: &mut Mat, size: Size, border: BorderType) -> Result<(), Error> {
+    ::cv_result(unsafe { ffi::PyrUp_Checked(src.inner, dst.inner, size, border as i32) })
 }
 
 /// GaussianBlur blurs an image Mat using a Gaussian filter.
@@ -225,8 +230,10 @@ pub fn gaussian_blur(
     sigma_x: f64,
     sigma_y: f64,
     border: BorderType,
-) {
-    unsafe { ffi::GaussianBlur(src.inner, dst.inner, ksize, sigma_x, sigma_y, border as i32) }
+) -> Result<(), Error> {
+    ::cv_result(unsafe {
+        ffi::GaussianBlur_Checked(src.inner, dst.inner, ksize, sigma_x, sigma_y, border as i32)
+    })
 }
 
 /// Calculates the Laplacian of an image.
@@ -238,9 +245,9 @@ pub fn laplacian(
     scale: f64,
     delta: f64,
     border: BorderType,
-) {
-    unsafe {
-        ffi::Laplacian(
+) -> Result<(), Error> {
+    ::cv_result(unsafe {
+        ffi::Laplacian_Checked(
             src.inner,
             dst.inner,
             depth,
@@ -249,7 +256,7 @@ pub fn laplacian(
             delta,
             border as i32,
         )
-    }
+    })
 }
 
 /// Calculates the first x- or y- image derivative using Scharr operator.
@@ -262,9 +269,9 @@ pub fn scharr(
     scale: f64,
     delta: f64,
     border: BorderType,
-) {
-    unsafe {
-        ffi::Scharr(
+) -> Result<(), Error> {
+    ::cv_result(unsafe {
+        ffi::Scharr_Checked(
             src.inner,
             dst.inner,
             depth,
@@ -274,12 +281,12 @@ pub fn scharr(
             delta,
             border as i32,
         )
-    }
+    })
 }
 
 /// Blurs an image using the median filter.
-pub fn median_blur(src: &Mat, dst: &mut Mat, size: i32) {
-    unsafe { ffi::MedianBlur(src.inner, dst.inner, size) }
+pub fn median_blur(src: &Mat, dst: &mut Mat, size: i32) -> Result<(), Error> {
+    ::cv_result(unsafe { ffi::MedianBlur_Checked(src.inner, dst.inner, size) })
 }
 
 /// Finds edges in an image using the Canny algorithm.
@@ -295,8 +302,8 @@ pub fn median_blur(src: &Mat, dst: &mut Mat, size: i32) {
 /// size as image .
 /// * `threshold1`: first threshold for the hysteresis procedure.
 /// * `threshold2`: second threshold for the hysteresis procedure.
-pub fn canny(src: &Mat, edges: &mut Mat, threshold1: f64, threshold2: f64) {
-    unsafe { ffi::Canny(src.inner, edges.inner, threshold1, threshold2) }
+pub fn canny(src: &Mat, edges: &mut Mat, threshold1: f64, threshold2: f64) -> Result<(), Error> {
+    ::cv_result(unsafe { ffi::Canny_Checked(src.inner, edges.inner, threshold1, threshold2) })
 }
 
 /// Determines strong corners on an image. The function finds the most prominent
@@ -307,8 +314,10 @@ pub fn good_features_to_track(
     max_corners: i32,
     quality: f64,
     min_dist: f64,
-) {
-    unsafe { ffi::GoodFeaturesToTrack(img.inner, corners.inner, max_corners, quality, min_dist) }
+) -> Result<(), Error> {
+    ::cv_result(unsafe {
+        ffi::GoodFeaturesToTrack_Checked(img.inner, corners.inner, max_corners, quality, min_dist)
+    })
 }
 
 /// Type of threshold operation.
@@ -334,13 +343,25 @@ pub enum ThresholdType {
 }
 
 /// Applies a fixed-level threshold to each array element.
-pub fn threshold(src: &Mat, dst: &mut Mat, thresh: f64, max: f64, type_: ThresholdType) {
-    unsafe { ffi::Threshold(src.inner, dst.inner, thresh, max, type_ as i32) }
+pub fn threshold(
+    src: &Mat,
+    dst: &mut Mat,
+    thresh: f64,
+    max: f64,
+    type_: ThresholdType,
+) -> Result<(), Error> {
+    ::cv_result(unsafe { ffi::Threshold_Checked(src.inner, dst.inner, thresh, max, type_ as i32) })
 }
 
 /// Draws a circle.
-pub fn circle(img: &mut Mat, center: Point, radius: i32, color: Scalar, thickness: i32) {
-    unsafe { ffi::Circle(img.inner, center, radius, color, thickness) }
+pub fn circle(
+    img: &mut Mat,
+    center: Point,
+    radius: i32,
+    color: Scalar,
+    thickness: i32,
+) -> Result<(), Error> {
+    ::cv_result(unsafe { ffi::Circle_Checked(img.inner, center, radius, color, thickness) })
 }
 
 /// Draws a simple or thick elliptic arc or fills an ellipse sector.
@@ -353,9 +374,9 @@ pub fn ellipse(
     end_angle: f64,
     color: Scalar,
     thickness: i32,
-) {
-    unsafe {
-        ffi::Ellipse(
+) -> Result<(), Error> {
+    ::cv_result(unsafe {
+        ffi::Ellipse_Checked(
             img.inner,
             center,
             axes,
@@ -365,18 +386,24 @@ pub fn ellipse(
             color,
             thickness,
         )
-    }
+    })
 }
 
 /// Draws a line segment connecting two points.
-pub fn line(img: &mut Mat, pt1: Point, pt2: Point, color: Scalar, thickness: i32) {
-    unsafe { ffi::Line(img.inner, pt1, pt2, color, thickness) }
+pub fn line(
+    img: &mut Mat,
+    pt1: Point,
+    pt2: Point,
+    color: Scalar,
+    thickness: i32,
+) -> Result<(), Error> {
+    ::cv_result(unsafe { ffi::Line_Checked(img.inner, pt1, pt2, color, thickness) })
 }
 
 /// Rectangle draws a simple, thick, or filled up-right rectangle.  It renders a
 /// rectangle with the desired characteristics to the target Mat image.
-pub fn rectangle(img: &mut Mat, r: Rect, c: Scalar, thickness: i32) {
-    unsafe { ffi::Rectangle(img.inner, r, c, thickness) }
+pub fn rectangle(img: &mut Mat, r: Rect, c: Scalar, thickness: i32) -> Result<(), Error> {
+    ::cv_result(unsafe { ffi::Rectangle_Checked(img.inner, r, c, thickness) })
 }
 
 /// Interpolation algorithm
@@ -411,6 +438,13 @@ pub enum InterpolationFlag {
 /// same image. If you wish to scale by factor, an empty sz may be passed and
 /// non-zero fx and fy. Likewise, if you wish to scale to an explicit size, a
 /// non-empty sz may be passed with zero for both fx and fy.
-pub fn resize(src: &Mat, dst: &mut Mat, sz: Size, fx: f64, fy: f64, interp: InterpolationFlag) {
-    unsafe { ffi::Resize(src.inner, dst.inner, sz, fx, fy, interp as i32) }
+pub fn resize(
+    src: &Mat,
+    dst: &mut Mat,
+    sz: Size,
+    fx: f64,
+    fy: f64,
+    interp: InterpolationFlag,
+) -> Result<(), Error> {
+    ::cv_result(unsafe { ffi::Resize_Checked(src.inner, dst.inner, sz, fx, fy, interp as i32) })
 }
@@ -11,8 +11,14 @@
 #![deny(unused_import_braces)]
 #![deny(unused_qualifications)]
 
+#[macro_use]
+extern crate bitflags;
 #[macro_use]
 extern crate failure;
+#[cfg(feature = "image")]
+extern crate image;
+#[cfg(feature = "ndarray")]
+extern crate ndarray;
 #[macro_use]
 extern crate num_derive;
 extern crate num_traits;
@@ -20,7 +26,8 @@ extern crate opencv_sys;
 
 use failure::Error;
 use std::path::{Path, PathBuf};
-use std::ffi::CString;
+use std::ffi::{CStr, CString};
+use std::os::raw::c_char;
 
 pub mod core;
 pub mod dnn;
@@ -29,10 +36,19 @@ pub mod highgui;
 pub mod imgcodecs;
 pub mod imgproc;
 pub mod objdetect;
+pub mod video;
+pub mod videoio;
 
 #[cfg(feature = "cuda")]
 pub mod cuda;
 
+mod mat_ops;
+
+#[cfg(feature = "image")]
+mod image_interop;
+#[cfg(feature = "ndarray")]
+mod ndarray_interop;
+
 #[derive(Debug, Fail)]
 /// Custom errors.
 pub enum CvError {
@@ -48,6 +64,105 @@ pub enum CvError {
     /// Indicates that cascade model was invalid
     InvalidCascadeModel(PathBuf),
 
+    #[fail(display = "failed to open video source: {:?}", _0)]
+    /// Indicates that the video file, image sequence or device could not be
+    /// opened
+    InvalidVideoSource(String),
+
+    #[fail(display = "failed to open video writer: {:?}", _0)]
+    /// Indicates that the video writer could not be opened for the given
+    /// file, codec and frame size
+    InvalidVideoSink(PathBuf),
+
+    #[fail(display = "invalid fourcc code: {:?}", _0)]
+    /// Indicates that a codec code is not four printable ASCII characters
+    InvalidFourCC(String),
+
+    #[fail(
+        display = "frame size mismatch: expected {:?}, got {:?}",
+        expected, actual
+    )]
+    /// Indicates that a frame does not have the expected size
+    FrameSizeMismatch {
+        /// Expected frame size
+        expected: core::Size,
+        /// Size of the offending frame
+        actual: core::Size,
+    },
+
+    #[fail(display = "channel mismatch: expected {}, got {}", expected, actual)]
+    /// Indicates that a frame does not have the expected number of channels
+    ChannelMismatch {
+        /// Expected number of channels
+        expected: i32,
+        /// Number of channels of the offending frame
+        actual: i32,
+    },
+
+    #[fail(display = "type mismatch: expected {:?}, got {:?}", expected, actual)]
+    /// Indicates that a Mat does not have the expected element type
+    TypeMismatch {
+        /// Expected type
+        expected: core::CvType,
+        /// Type of the offending Mat
+        actual: core::CvType,
+    },
+
+    #[fail(display = "invalid number of channels: {}", _0)]
+    /// Indicates that a number of channels is not between 1 and
+    /// `CvType::CN_MAX`
+    InvalidChannels(i32),
+
+    #[fail(display = "a {} x {} array is too large for a Mat", rows, cols)]
+    /// Indicates that an array or image has more rows or columns than a Mat
+    /// can hold
+    SizeTooLarge {
+        /// Number of rows of the offending array
+        rows: usize,
+        /// Number of columns of the offending array
+        cols: usize,
+    },
+
+    #[fail(display = "dimension mismatch: expected {}, got {}", expected, actual)]
+    /// Indicates that a Mat does not have the expected number of dimensions,
+    /// or that a slice of points does not have the expected length
+    DimensionMismatch {
+        /// Expected number of dimensions or elements
+        expected: i32,
+        /// Number of dimensions or elements of the offending argument
+        actual: i32,
+    },
+
+    #[fail(display = "the Mat is empty")]
+    /// Indicates that a Mat without any elements was passed where data is
+    /// needed
+    EmptyMat,
+
+    #[fail(display = "index {:?} out of bounds for shape {:?}", index, shape)]
+    /// Indicates that an element outside of a Mat was accessed
+    OutOfBounds {
+        /// Index of the element, one coordinate per dimension
+        index: Vec<i32>,
+        /// Size of each dimension of the Mat, where the columns of a 2D Mat
+        /// count single channel values
+        shape: Vec<i32>,
+    },
+
+    #[fail(display = "OpenCV error {} in {}: {}", code, func, message)]
+    /// Indicates that OpenCV raised a `cv::Exception`
+    OpenCv {
+        /// OpenCV status code (`cv::Error::Code`)
+        code: i32,
+        /// Name of the function that raised the exception
+        func: String,
+        /// Source file that raised the exception
+        file: String,
+        /// Line in the source file
+        line: i32,
+        /// Error description
+        message: String,
+    },
+
     #[fail(display = "EntryNotFound: {:?}", _0)]
     /// Indicates that there is no entry on specified path
     EntryNotFound(PathBuf),
@@ -71,3 +186,29 @@ fn path_to_cstring<P: AsRef<Path>>(path: P) -> Result<CString, Error> {
     let result = CString::new(x)?;
     Ok(result)
 }
+
+fn cv_string(s: *const c_char) -> String {
+    if s.is_null() {
+        String::new()
+    } else {
+        unsafe { CStr::from_ptr(s) }.to_string_lossy().into_owned()
+    }
+}
+
+/// Converts the status returned by a checked shim into a `Result`, releasing
+/// the strings it owns.
+fn cv_result(status: opencv_sys::CvStatus) -> Result<(), Error> {
+    if status.code == 0 {
+        return Ok(());
+    }
+
+    let err = CvError::OpenCv {
+        code: status.code,
+        func: cv_string(status.func),
+        file: cv_string(status.file),
+        line: status.line,
+        message: cv_string(status.err),
+    };
+    unsafe { opencv_sys::CvStatus_Close(status) };
+    Err(err.into())
+}
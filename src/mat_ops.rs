@@ -0,0 +1,245 @@
+//! Arithmetic and bitwise operators for [Mat](../core/struct.Mat.html).
+//!
+//! The operators call the corresponding OpenCV functions (`cv::add`,
+//! `cv::multiply`, `cv::bitwise_and`, ...), so results saturate to the range
+//! of the Mat type, e.g. `200 + 100` is `255` for a `Cv8UC1` Mat. A `Scalar`
+//! operand is applied per channel, an `f64` operand to every channel.
+//! Both forms of division follow `cv::divide`, so dividing an integer Mat by
+//! zero yields zero.
+//!
+//! Operators cannot return errors, so they panic if OpenCV rejects the
+//! operands (e.g. Mats of different sizes or types). Use the functions in
+//! [core](../core/index.html) to handle such errors instead.
+
+use core::{Mat, Scalar};
+use opencv_sys as ffi;
+use std::ptr;
+use std::ops::{Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign};
+use std::ops::{Div, DivAssign, Mul, MulAssign, Neg, Not, Sub, SubAssign};
+
+/// Panics with the message of the OpenCV error, if any.
+fn check(status: ffi::CvStatus) {
+    if let Err(e) = ::cv_result(status) {
+        panic!("{}", e);
+    }
+}
+
+unsafe fn add(src1: ffi::Mat, src2: ffi::Mat, dst: ffi::Mat) -> ffi::CvStatus {
+    ffi::Mat_Add_Checked(src1, src2, dst, ptr::null_mut(), -1)
+}
+
+unsafe fn subtract(src1: ffi::Mat, src2: ffi::Mat, dst: ffi::Mat) -> ffi::CvStatus {
+    ffi::Mat_Subtract_Checked(src1, src2, dst, ptr::null_mut(), -1)
+}
+
+unsafe fn multiply(src1: ffi::Mat, src2: ffi::Mat, dst: ffi::Mat) -> ffi::CvStatus {
+    ffi::Mat_Multiply_Checked(src1, src2, dst, 1.0, -1)
+}
+
+unsafe fn divide(src1: ffi::Mat, src2: ffi::Mat, dst: ffi::Mat) -> ffi::CvStatus {
+    ffi::Mat_Divide_Checked(src1, src2, dst, 1.0, -1)
+}
+
+/// Returns a Scalar with all four values set to `v`.
+fn scalar_all(v: f64) -> Scalar {
+    Scalar {
+        val1: v,
+        val2: v,
+        val3: v,
+        val4: v,
+    }
+}
+
+/// Implements `$op<$rhs>` for `&Mat` and `Mat` as well as `$op_assign<$rhs>`
+/// for `Mat` by calling `$f(src, rhs, dst)`, where `$arg` converts the right
+/// hand side to the argument of `$f`. Lifetimes used by `$rhs` are listed
+/// in the leading `<...>`.
+macro_rules! impl_op {
+    (<$($lt:lifetime),*> $op:ident, $method:ident, $op_assign:ident, $method_assign:ident,
+     $rhs:ty, |$r:ident| $arg:expr, $f:path) => {
+        impl<$($lt),*> $op<$rhs> for &Mat {
+            type Output = Mat;
+
+            fn $method(self, $r: $rhs) -> Mat {
+                let dst = Mat::new();
+                check(unsafe { $f(self.inner, $arg, dst.inner) });
+                dst
+            }
+        }
+
+        impl<$($lt),*> $op<$rhs> for Mat {
+            type Output = Mat;
+
+            fn $method(self, $r: $rhs) -> Mat {
+                (&self).$method($r)
+            }
+        }
+
+        impl<$($lt),*> $op_assign<$rhs> for Mat {
+            fn $method_assign(&mut self, $r: $rhs) {
+                check(unsafe { $f(self.inner, $arg, self.inner) });
+            }
+        }
+    };
+}
+
+/// Implements `$op` between Mats, by reference or by value, and with a
+/// `Scalar`.
+macro_rules! impl_mat_op {
+    ($op:ident, $method:ident, $op_assign:ident, $method_assign:ident, $f:path, $f_scalar:path) => {
+        impl_op!(
+            <'b> $op,
+            $method,
+            $op_assign,
+            $method_assign,
+            &'b Mat,
+            |rhs| rhs.inner,
+            $f
+        );
+        impl_op!(
+            <> $op,
+            $method,
+            $op_assign,
+            $method_assign,
+            Scalar,
+            |rhs| rhs,
+            $f_scalar
+        );
+
+        impl $op<Mat> for &Mat {
+            type Output = Mat;
+
+            fn $method(self, rhs: Mat) -> Mat {
+                self.$method(&rhs)
+            }
+        }
+
+        impl $op<Mat> for Mat {
+            type Output = Mat;
+
+            fn $method(self, rhs: Mat) -> Mat {
+                (&self).$method(&rhs)
+            }
+        }
+
+        impl $op_assign<Mat> for Mat {
+            fn $method_assign(&mut self, rhs: Mat) {
+                self.$method_assign(&rhs);
+            }
+        }
+    };
+}
+
+impl_mat_op!(
+    Add,
+    add,
+    AddAssign,
+    add_assign,
+    add,
+    ffi::Mat_AddScalar_Checked
+);
+impl_mat_op!(
+    Sub,
+    sub,
+    SubAssign,
+    sub_assign,
+    subtract,
+    ffi::Mat_SubtractScalar_Checked
+);
+impl_mat_op!(
+    Mul,
+    mul,
+    MulAssign,
+    mul_assign,
+    multiply,
+    ffi::Mat_MultiplyScalar_Checked
+);
+impl_mat_op!(
+    Div,
+    div,
+    DivAssign,
+    div_assign,
+    divide,
+    ffi::Mat_DivideScalar_Checked
+);
+impl_mat_op!(
+    BitAnd,
+    bitand,
+    BitAndAssign,
+    bitand_assign,
+    ffi::Mat_BitwiseAnd_Checked,
+    ffi::Mat_BitwiseAndScalar_Checked
+);
+impl_mat_op!(
+    BitOr,
+    bitor,
+    BitOrAssign,
+    bitor_assign,
+    ffi::Mat_BitwiseOr_Checked,
+    ffi::Mat_BitwiseOrScalar_Checked
+);
+impl_mat_op!(
+    BitXor,
+    bitxor,
+    BitXorAssign,
+    bitxor_assign,
+    ffi::Mat_BitwiseXor_Checked,
+    ffi::Mat_BitwiseXorScalar_Checked
+);
+
+impl_op!(
+    <> Mul,
+    mul,
+    MulAssign,
+    mul_assign,
+    f64,
+    |rhs| rhs,
+    ffi::Mat_Scale_Checked
+);
+impl_op!(
+    <> Div,
+    div,
+    DivAssign,
+    div_assign,
+    f64,
+    |rhs| scalar_all(rhs),
+    ffi::Mat_DivideScalar_Checked
+);
+
+impl Neg for &Mat {
+    type Output = Mat;
+
+    fn neg(self) -> Mat {
+        let dst = Mat::new();
+        check(unsafe {
+            ffi::Mat_SubtractFromScalar_Checked(scalar_all(0.0), self.inner, dst.inner)
+        });
+        dst
+    }
+}
+
+impl Neg for Mat {
+    type Output = Mat;
+
+    fn neg(self) -> Mat {
+        -&self
+    }
+}
+
+impl Not for &Mat {
+    type Output = Mat;
+
+    fn not(self) -> Mat {
+        let dst = Mat::new();
+        check(unsafe { ffi::Mat_BitwiseNot_Checked(self.inner, dst.inner) });
+        dst
+    }
+}
+
+impl Not for Mat {
+    type Output = Mat;
+
+    fn not(self) -> Mat {
+        !&self
+    }
+}
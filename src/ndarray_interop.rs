@@ -0,0 +1,136 @@
+//! Conversions between [Mat](../core/struct.Mat.html) and
+//! [ndarray](https://docs.rs/ndarray) arrays, enabled by the `ndarray`
+//! feature.
+
+use core::{CvType, Element, Mat};
+use ndarray::{Array3, ArrayView2, ArrayView3, ArrayViewMut2, ArrayViewMut3, ShapeBuilder};
+use std::cmp;
+use std::mem::size_of;
+use {CvError, Error};
+
+/// Shape and strides of a 2D Mat, in units of `T`.
+struct Layout {
+    rows: usize,
+    cols: usize,
+    row_stride: usize,
+}
+
+impl Mat {
+    /// Returns a rows × cols view of this Mat without copying. `T` has to
+    /// match the type of the Mat exactly, e.g. `f32` for `Cv32FC1` or
+    /// `[u8; 3]` for `Cv8UC3`.
+    ///
+    /// Regions are supported, the rows of the view are `cv_step()` bytes
+    /// apart.
+    pub fn array_view2<'a, T: Element>(&'a self) -> Result<ArrayView2<'a, T>, Error> {
+        let layout = self.layout::<T>(T::cv_type())?;
+        let shape = (layout.rows, layout.cols).strides((layout.row_stride, 1));
+        Ok(unsafe { ArrayView2::from_shape_ptr(shape, self.data_ptr() as *const T) })
+    }
+
+    /// Mutable version of [array_view2](#method.array_view2).
+    pub fn array_view2_mut<'a, T: Element>(&'a mut self) -> Result<ArrayViewMut2<'a, T>, Error> {
+        let layout = self.layout::<T>(T::cv_type())?;
+        let shape = (layout.rows, layout.cols).strides((layout.row_stride, 1));
+        Ok(unsafe { ArrayViewMut2::from_shape_ptr(shape, self.data_ptr() as *mut T) })
+    }
+
+    /// Returns a rows × cols × channels view of this Mat without copying. `T`
+    /// is the type of a single channel and has to match the depth of the Mat,
+    /// e.g. `u8` for `Cv8UC3`.
+    pub fn array_view3<'a, T: Element>(&'a self) -> Result<ArrayView3<'a, T>, Error> {
+        let channels = self.channels() as usize;
+        let layout = self.channel_layout::<T>()?;
+        let shape = (layout.rows, layout.cols / channels, channels).strides((
+            layout.row_stride,
+            channels,
+            1,
+        ));
+        Ok(unsafe { ArrayView3::from_shape_ptr(shape, self.data_ptr() as *const T) })
+    }
+
+    /// Mutable version of [array_view3](#method.array_view3).
+    pub fn array_view3_mut<'a, T: Element>(&'a mut self) -> Result<ArrayViewMut3<'a, T>, Error> {
+        let channels = self.channels() as usize;
+        let layout = self.channel_layout::<T>()?;
+        let shape = (layout.rows, layout.cols / channels, channels).strides((
+            layout.row_stride,
+            channels,
+            1,
+        ));
+        Ok(unsafe { ArrayViewMut3::from_shape_ptr(shape, self.data_ptr() as *mut T) })
+    }
+
+    /// Copies a rows × cols × channels array into a new Mat. `T` is the type
+    /// of a single channel, e.g. `u8` for a `Cv8UC3` Mat.
+    ///
+    /// Fails if the number of channels is not between 1 and `CvType::CN_MAX`
+    /// or if the array has more rows or columns than a Mat can hold.
+    pub fn from_array3<T: Element>(array: &Array3<T>) -> Result<Mat, Error> {
+        if T::CHANNELS != 1 {
+            return Err(CvError::TypeMismatch {
+                expected: CvType::from(T::DEPTH),
+                actual: T::cv_type(),
+            }
+            .into());
+        }
+        let (rows, cols, channels) = array.dim();
+        if rows > i32::MAX as usize || cols > i32::MAX as usize {
+            return Err(CvError::SizeTooLarge { rows, cols }.into());
+        }
+        let t = CvType::try_new(T::DEPTH, cmp::min(channels, i32::MAX as usize) as i32)?;
+        let mut mat = Mat::new_with_size(rows as i32, cols as i32, t)?;
+        if !array.is_empty() {
+            mat.array_view3_mut::<T>()?.assign(array);
+        }
+        Ok(mat)
+    }
+
+    /// Like `layout`, but for a single channel `T` that matches the depth of
+    /// this Mat.
+    fn channel_layout<T: Element>(&self) -> Result<Layout, Error> {
+        if T::CHANNELS != 1 {
+            return Err(CvError::TypeMismatch {
+                expected: T::cv_type(),
+                actual: self.cv_type(),
+            }
+            .into());
+        }
+        self.layout::<T>(CvType::new(T::DEPTH, self.channels()))
+    }
+
+    /// Checks that this Mat is a non-empty 2D Mat of the given type and
+    /// returns its layout in units of `T`.
+    fn layout<T>(&self, expected: CvType) -> Result<Layout, Error> {
+        if self.empty() {
+            return Err(CvError::EmptyMat.into());
+        }
+        let actual = self.cv_type();
+        if actual != expected {
+            return Err(CvError::TypeMismatch { expected, actual }.into());
+        }
+        let dims = self.dims();
+        if dims != 2 {
+            return Err(CvError::DimensionMismatch {
+                expected: 2,
+                actual: dims,
+            }
+            .into());
+        }
+
+        let size = size_of::<T>();
+        let step = self.cv_step() as usize;
+        if step % size != 0 {
+            return Err(CvError::UnknownError(format!(
+                "row step of {} bytes is not a multiple of the element size",
+                step
+            ))
+            .into());
+        }
+        Ok(Layout {
+            rows: self.rows() as usize,
+            cols: self.cols() as usize * expected.elem_size() / size,
+            row_stride: step / size,
+        })
+    }
+}
@@ -6,6 +6,7 @@ use Error;
 use CvError;
 use core::{Mat, Rect, Size};
 use std::path::Path;
+use std::ptr;
 
 /// Cascade classifier class for object detection.
 #[derive(Debug)]
@@ -25,7 +26,10 @@ impl CascadeClassifier {
     pub fn load<P: AsRef<Path>>(&mut self, path: P) -> Result<(), Error> {
         if let Some(p) = path.as_ref().to_str() {
             let s = CString::new(p)?;
-            let r = unsafe { ffi::CascadeClassifier_Load(self.inner, (&s).as_ptr()) };
+            let mut r = 0;
+            ::cv_result(unsafe {
+                ffi::CascadeClassifier_Load_Checked(self.inner, (&s).as_ptr(), &mut r)
+            })?;
             if r != 0 {
                 Ok(())
             } else {
@@ -38,11 +42,12 @@ impl CascadeClassifier {
 
     /// Detects objects of different sizes in the input Mat image. The detected
     /// objects are returned as a vector of rectangles.
-    pub fn detect_multiscale(&self, mat: &Mat) -> Vec<Rect> {
-        let rects = unsafe { ffi::CascadeClassifier_DetectMultiScale(self.inner, mat.inner) };
-        (0..(rects.length as isize))
-            .map(|i| unsafe { *(rects.rects.offset(i)) })
-            .collect()
+    pub fn detect_multiscale(&self, mat: &Mat) -> Result<Vec<Rect>, Error> {
+        let mut rects = empty_rects();
+        ::cv_result(unsafe {
+            ffi::CascadeClassifier_DetectMultiScale_Checked(self.inner, mat.inner, &mut rects)
+        })?;
+        Ok(get_rects(rects))
     }
 
     /// Detects the object using parameters specified.
@@ -66,9 +71,10 @@ impl CascadeClassifier {
         min_neighbors: i32,
         min_size: Size,
         max_size: Size,
-    ) -> Vec<Rect> {
-        let rects = unsafe {
-            ffi::CascadeClassifier_DetectMultiScaleWithParams(
+    ) -> Result<Vec<Rect>, Error> {
+        let mut rects = empty_rects();
+        ::cv_result(unsafe {
+            ffi::CascadeClassifier_DetectMultiScaleWithParams_Checked(
                 self.inner,
                 mat.inner,
                 scale,
@@ -76,14 +82,26 @@ impl CascadeClassifier {
                 0,
                 min_size,
                 max_size,
+                &mut rects,
             )
-        };
-        (0..(rects.length as isize))
-            .map(|i| unsafe { *(rects.rects.offset(i)) })
-            .collect()
+        })?;
+        Ok(get_rects(rects))
     }
 }
 
+fn empty_rects() -> ffi::Rects {
+    ffi::Rects {
+        rects: ptr::null_mut(),
+        length: 0,
+    }
+}
+
+fn get_rects(rects: ffi::Rects) -> Vec<Rect> {
+    (0..(rects.length as isize))
+        .map(|i| unsafe { *(rects.rects.offset(i)) })
+        .collect()
+}
+
 impl Drop for CascadeClassifier {
     fn drop(&mut self) {
         unsafe { ffi::CascadeClassifier_Close(self.inner) }
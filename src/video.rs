@@ -0,0 +1,512 @@
+//! [Video Analysis](https://docs.opencv.org/master/d7/de9/group__video.html).
+
+use opencv_sys as ffi;
+use core::{to_points2f, CvType, Mat, Point2f, Rect, RotatedRect, Size, Size2f, TermCriteria};
+use std::ptr;
+use {CvError, Error};
+
+/// Gaussian Mixture-based Background/Foreground Segmentation Algorithm.
+///
+/// The class implements the Gaussian mixture model background subtraction
+/// described in Zivkovic2004 and Zivkovic2006.
+#[derive(Debug)]
+pub struct BackgroundSubtractorMOG2 {
+    inner: ffi::BackgroundSubtractorMOG2,
+}
+
+impl Drop for BackgroundSubtractorMOG2 {
+    fn drop(&mut self) {
+        unsafe { ffi::BackgroundSubtractorMOG2_Close(self.inner) }
+    }
+}
+
+impl BackgroundSubtractorMOG2 {
+    /// Creates a MOG2 background subtractor with the default parameters
+    /// (history of 500 frames, variance threshold of 16 and shadow detection
+    /// enabled).
+    pub fn new() -> Self {
+        BackgroundSubtractorMOG2 {
+            inner: unsafe { ffi::BackgroundSubtractorMOG2_Create() },
+        }
+    }
+
+    /// Creates a MOG2 background subtractor.
+    ///
+    /// * `history`: length of the history.
+    /// * `var_threshold`: threshold on the squared Mahalanobis distance
+    ///   between the pixel and the model to decide whether a pixel is well
+    ///   described by the background model.
+    /// * `detect_shadows`: if true, the algorithm will detect shadows and mark
+    ///   them (with value 127). It decreases the speed a bit, so if you do not
+    ///   need this feature, set the parameter to false.
+    pub fn with_params(history: i32, var_threshold: f64, detect_shadows: bool) -> Self {
+        BackgroundSubtractorMOG2 {
+            inner: unsafe {
+                ffi::BackgroundSubtractorMOG2_CreateWithParams(
+                    history,
+                    var_threshold,
+                    detect_shadows,
+                )
+            },
+        }
+    }
+
+    /// Computes a foreground mask.
+    ///
+    /// * `src`: next video frame.
+    /// * `dst`: the output foreground mask as an 8-bit binary image.
+    /// * `learning_rate`: value between 0 and 1 that indicates how fast the
+    ///   background model is learnt. Negative value makes the algorithm to use
+    ///   some automatically chosen learning rate. 0 means that the background
+    ///   model is not updated at all, 1 means that the background model is
+    ///   completely reinitialized from the last frame.
+    pub fn apply(&mut self, src: &Mat, dst: &mut Mat, learning_rate: f64) -> Result<(), Error> {
+        ::cv_result(unsafe {
+            ffi::BackgroundSubtractorMOG2_ApplyWithLearningRate(
+                self.inner,
+                src.inner,
+                dst.inner,
+                learning_rate,
+            )
+        })
+    }
+
+    /// Computes a background image.
+    pub fn background_image(&self) -> Result<Mat, Error> {
+        let dst = Mat::new();
+        ::cv_result(unsafe {
+            ffi::BackgroundSubtractorMOG2_GetBackgroundImage(self.inner, dst.inner)
+        })?;
+        Ok(dst)
+    }
+}
+
+/// K-nearest neighbours - based Background/Foreground Segmentation Algorithm.
+///
+/// The class implements the K-nearest neighbours background subtraction
+/// described in Zivkovic2006. Very efficient if number of foreground pixels is
+/// low.
+#[derive(Debug)]
+pub struct BackgroundSubtractorKNN {
+    inner: ffi::BackgroundSubtractorKNN,
+}
+
+impl Drop for BackgroundSubtractorKNN {
+    fn drop(&mut self) {
+        unsafe { ffi::BackgroundSubtractorKNN_Close(self.inner) }
+    }
+}
+
+impl BackgroundSubtractorKNN {
+    /// Creates a KNN background subtractor with the default parameters
+    /// (history of 500 frames, distance threshold of 400 and shadow detection
+    /// enabled).
+    pub fn new() -> Self {
+        BackgroundSubtractorKNN {
+            inner: unsafe { ffi::BackgroundSubtractorKNN_Create() },
+        }
+    }
+
+    /// Creates a KNN background subtractor.
+    ///
+    /// * `history`: length of the history.
+    /// * `dist2_threshold`: threshold on the squared distance between the
+    ///   pixel and the sample to decide whether a pixel is close to that
+    ///   sample.
+    /// * `detect_shadows`: if true, the algorithm will detect shadows and mark
+    ///   them (with value 127). It decreases the speed a bit, so if you do not
+    ///   need this feature, set the parameter to false.
+    pub fn with_params(history: i32, dist2_threshold: f64, detect_shadows: bool) -> Self {
+        BackgroundSubtractorKNN {
+            inner: unsafe {
+                ffi::BackgroundSubtractorKNN_CreateWithParams(
+                    history,
+                    dist2_threshold,
+                    detect_shadows,
+                )
+            },
+        }
+    }
+
+    /// Computes a foreground mask. See
+    /// [BackgroundSubtractorMOG2::apply](struct.BackgroundSubtractorMOG2.html#method.apply)
+    /// for the meaning of the arguments.
+    pub fn apply(&mut self, src: &Mat, dst: &mut Mat, learning_rate: f64) -> Result<(), Error> {
+        ::cv_result(unsafe {
+            ffi::BackgroundSubtractorKNN_ApplyWithLearningRate(
+                self.inner,
+                src.inner,
+                dst.inner,
+                learning_rate,
+            )
+        })
+    }
+
+    /// Computes a background image.
+    pub fn background_image(&self) -> Result<Mat, Error> {
+        let dst = Mat::new();
+        ::cv_result(unsafe {
+            ffi::BackgroundSubtractorKNN_GetBackgroundImage(self.inner, dst.inner)
+        })?;
+        Ok(dst)
+    }
+}
+
+/// Finds an object on a back projection image.
+///
+/// * `prob_image`: back projection of the object histogram.
+/// * `window`: initial search window.
+/// * `criteria`: stop criteria for the iterative search algorithm.
+///
+/// Returns the search window after the object center has been shifted to the
+/// mass center of the back projection inside of it. The size of the window is
+/// not changed.
+pub fn mean_shift(prob_image: &Mat, window: Rect, criteria: TermCriteria) -> Result<Rect, Error> {
+    let mut window = window;
+    let mut iterations = 0;
+    ::cv_result(unsafe {
+        ffi::MeanShift(
+            prob_image.inner,
+            &mut window,
+            criteria.into(),
+            &mut iterations,
+        )
+    })?;
+    Ok(window)
+}
+
+/// Finds an object center, size, and orientation on a back projection image
+/// using the CAMSHIFT algorithm.
+///
+/// First, it finds an object center using
+/// [mean_shift](fn.mean_shift.html) and then adjusts the window size and
+/// finds the optimal rotation. Returns the rotated rectangle of the object
+/// together with the updated search window, which can be passed to the next
+/// call.
+pub fn cam_shift(
+    prob_image: &Mat,
+    window: Rect,
+    criteria: TermCriteria,
+) -> Result<(RotatedRect, Rect), Error> {
+    let mut window = window;
+    let mut rect = RotatedRect {
+        center: Point2f { x: 0.0, y: 0.0 },
+        size: Size2f {
+            width: 0.0,
+            height: 0.0,
+        },
+        angle: 0.0,
+    };
+    ::cv_result(unsafe {
+        ffi::CamShift(prob_image.inner, &mut window, criteria.into(), &mut rect)
+    })?;
+    Ok((rect, window))
+}
+
+/// Kalman filter class.
+///
+/// The class implements a standard Kalman filter
+/// <http://en.wikipedia.org/wiki/Kalman_filter>. However, you can modify
+/// `transition_matrix`, `control_matrix`, and `measurement_matrix` to get an
+/// extended Kalman filter functionality.
+///
+/// The matrix getters return a copy of the matrix, so changing the returned
+/// `Mat` does not affect the filter. Use the setters, which copy the given
+/// matrix into the filter, to change it.
+#[derive(Debug)]
+pub struct KalmanFilter {
+    inner: ffi::KalmanFilter,
+}
+
+impl Drop for KalmanFilter {
+    fn drop(&mut self) {
+        unsafe { ffi::KalmanFilter_Close(self.inner) }
+    }
+}
+
+impl KalmanFilter {
+    /// Creates a Kalman filter.
+    ///
+    /// * `dynam_params`: dimensionality of the state.
+    /// * `measure_params`: dimensionality of the measurement.
+    /// * `control_params`: dimensionality of the control vector.
+    /// * `t`: type of the created matrices that should be `Cv32FC1` or
+    ///   `Cv64FC1`.
+    ///
+    /// Fails if `dynam_params` is not positive or `t` is not a floating point
+    /// type.
+    pub fn new(
+        dynam_params: i32,
+        measure_params: i32,
+        control_params: i32,
+        t: CvType,
+    ) -> Result<KalmanFilter, Error> {
+        let mut inner = ptr::null_mut();
+        ::cv_result(unsafe {
+            ffi::KalmanFilter_New(
+                dynam_params,
+                measure_params,
+                control_params,
+                t.to_i32(),
+                &mut inner,
+            )
+        })?;
+        Ok(KalmanFilter { inner })
+    }
+
+    /// Computes a predicted state, optionally using a control vector.
+    pub fn predict(&mut self, control: Option<&Mat>) -> Result<Mat, Error> {
+        let mut result = ptr::null_mut();
+        ::cv_result(unsafe {
+            match control {
+                Some(control) => {
+                    ffi::KalmanFilter_PredictWithControl(self.inner, control.inner, &mut result)
+                }
+                None => ffi::KalmanFilter_Predict(self.inner, &mut result),
+            }
+        })?;
+        Ok(Mat::from(result))
+    }
+
+    /// Updates the predicted state from the measurement and returns the
+    /// corrected state.
+    pub fn correct(&mut self, measurement: &Mat) -> Result<Mat, Error> {
+        let mut result = ptr::null_mut();
+        ::cv_result(unsafe {
+            ffi::KalmanFilter_Correct(self.inner, measurement.inner, &mut result)
+        })?;
+        Ok(Mat::from(result))
+    }
+
+    /// Returns the predicted state (x'(k)): x(k)=A\*x(k-1)+B\*u(k).
+    pub fn state_pre(&self) -> Mat {
+        Mat::from(unsafe { ffi::KalmanFilter_GetStatePre(self.inner) }).clone()
+    }
+
+    /// Sets the predicted state.
+    pub fn set_state_pre(&mut self, m: &Mat) -> Result<(), Error> {
+        ::cv_result(unsafe { ffi::KalmanFilter_SetStatePre(self.inner, m.inner) })
+    }
+
+    /// Returns the corrected state (x(k)): x(k)=x'(k)+K(k)\*(z(k)-H\*x'(k)).
+    pub fn state_post(&self) -> Mat {
+        Mat::from(unsafe { ffi::KalmanFilter_GetStatePost(self.inner) }).clone()
+    }
+
+    /// Sets the corrected state.
+    pub fn set_state_post(&mut self, m: &Mat) -> Result<(), Error> {
+        ::cv_result(unsafe { ffi::KalmanFilter_SetStatePost(self.inner, m.inner) })
+    }
+
+    /// Returns the state transition matrix (A).
+    pub fn transition_matrix(&self) -> Mat {
+        Mat::from(unsafe { ffi::KalmanFilter_GetTransitionMatrix(self.inner) }).clone()
+    }
+
+    /// Sets the state transition matrix.
+    pub fn set_transition_matrix(&mut self, m: &Mat) -> Result<(), Error> {
+        ::cv_result(unsafe { ffi::KalmanFilter_SetTransitionMatrix(self.inner, m.inner) })
+    }
+
+    /// Returns the control matrix (B) (not used if there is no control).
+    pub fn control_matrix(&self) -> Mat {
+        Mat::from(unsafe { ffi::KalmanFilter_GetControlMatrix(self.inner) }).clone()
+    }
+
+    /// Sets the control matrix.
+    pub fn set_control_matrix(&mut self, m: &Mat) -> Result<(), Error> {
+        ::cv_result(unsafe { ffi::KalmanFilter_SetControlMatrix(self.inner, m.inner) })
+    }
+
+    /// Returns the measurement matrix (H).
+    pub fn measurement_matrix(&self) -> Mat {
+        Mat::from(unsafe { ffi::KalmanFilter_GetMeasurementMatrix(self.inner) }).clone()
+    }
+
+    /// Sets the measurement matrix.
+    pub fn set_measurement_matrix(&mut self, m: &Mat) -> Result<(), Error> {
+        ::cv_result(unsafe { ffi::KalmanFilter_SetMeasurementMatrix(self.inner, m.inner) })
+    }
+
+    /// Returns the process noise covariance matrix (Q).
+    pub fn process_noise_cov(&self) -> Mat {
+        Mat::from(unsafe { ffi::KalmanFilter_GetProcessNoiseCov(self.inner) }).clone()
+    }
+
+    /// Sets the process noise covariance matrix.
+    pub fn set_process_noise_cov(&mut self, m: &Mat) -> Result<(), Error> {
+        ::cv_result(unsafe { ffi::KalmanFilter_SetProcessNoiseCov(self.inner, m.inner) })
+    }
+
+    /// Returns the measurement noise covariance matrix (R).
+    pub fn measurement_noise_cov(&self) -> Mat {
+        Mat::from(unsafe { ffi::KalmanFilter_GetMeasurementNoiseCov(self.inner) }).clone()
+    }
+
+    /// Sets the measurement noise covariance matrix.
+    pub fn set_measurement_noise_cov(&mut self, m: &Mat) -> Result<(), Error> {
+        ::cv_result(unsafe { ffi::KalmanFilter_SetMeasurementNoiseCov(self.inner, m.inner) })
+    }
+
+    /// Returns the priori error estimate covariance matrix (P'(k)): P'(k)=A\*P(k-1)\*At + Q.
+    pub fn error_cov_pre(&self) -> Mat {
+        Mat::from(unsafe { ffi::KalmanFilter_GetErrorCovPre(self.inner) }).clone()
+    }
+
+    /// Sets the priori error estimate covariance matrix.
+    pub fn set_error_cov_pre(&mut self, m: &Mat) -> Result<(), Error> {
+        ::cv_result(unsafe { ffi::KalmanFilter_SetErrorCovPre(self.inner, m.inner) })
+    }
+
+    /// Returns the Kalman gain matrix (K(k)): K(k)=P'(k)\*Ht\*inv(H\*P'(k)\*Ht+R).
+    pub fn gain(&self) -> Mat {
+        Mat::from(unsafe { ffi::KalmanFilter_GetGain(self.inner) }).clone()
+    }
+
+    /// Sets the Kalman gain matrix.
+    pub fn set_gain(&mut self, m: &Mat) -> Result<(), Error> {
+        ::cv_result(unsafe { ffi::KalmanFilter_SetGain(self.inner, m.inner) })
+    }
+
+    /// Returns the posteriori error estimate covariance matrix (P(k)): P(k)=(I-K(k)\*H)\*P'(k).
+    pub fn error_cov_post(&self) -> Mat {
+        Mat::from(unsafe { ffi::KalmanFilter_GetErrorCovPost(self.inner) }).clone()
+    }
+
+    /// Sets the posteriori error estimate covariance matrix.
+    pub fn set_error_cov_post(&mut self, m: &Mat) -> Result<(), Error> {
+        ::cv_result(unsafe { ffi::KalmanFilter_SetErrorCovPost(self.inner, m.inner) })
+    }
+}
+
+bitflags! {
+    /// Operation flags for the optical flow algorithms.
+    pub struct OpticalFlowFlags: i32 {
+        /// Uses the initial estimations stored in `next_pts` (Lucas-Kanade)
+        /// instead of starting from the previous positions.
+        const USE_INITIAL_FLOW = 4;
+        /// Uses the minimum eigen value of the 2x2 normal matrix of the
+        /// optical flow equations as the error measure (Lucas-Kanade).
+        const LK_GET_MIN_EIGENVALS = 8;
+        /// Uses the Gaussian winsize x winsize filter instead of a box filter
+        /// of the same size for optical flow estimation (Farneback).
+        const FARNEBACK_GAUSSIAN = 256;
+    }
+}
+
+/// Calculates an optical flow for a sparse feature set using the iterative
+/// Lucas-Kanade method with pyramids.
+///
+/// * `prev`: first 8-bit input image or pyramid.
+/// * `next`: second input image or pyramid of the same size and the same type
+///   as `prev`.
+/// * `prev_pts`: points for which the flow needs to be found, e.g. the result
+///   of [good_features_to_track](../imgproc/fn.good_features_to_track.html)
+///   converted with [Mat::to_points2f](../core/struct.Mat.html#method.to_points2f).
+/// * `next_pts`: initial estimations of the new positions, only used when
+///   `flags` contains `USE_INITIAL_FLOW`. Fails with
+///   `CvError::DimensionMismatch` if it is given but does not have the same
+///   length as `prev_pts`.
+/// * `win_size`: size of the search window at each pyramid level.
+/// * `max_level`: 0-based maximal pyramid level number; if set to 0, pyramids
+///   are not used (single level), if set to 1, two levels are used, and so on.
+/// * `criteria`: termination criteria of the iterative search algorithm.
+/// * `min_eig_threshold`: the algorithm calculates the minimum eigen value of
+///   a 2x2 normal matrix of optical flow equations, divided by number of
+///   pixels in a window; if this value is less than `min_eig_threshold`, then
+///   a corresponding feature is filtered out and its flow is not processed.
+///
+/// Returns the tracked points, a status vector (each element is set to 1 if
+/// the flow for the corresponding feature has been found, otherwise 0) and
+/// the error for each feature.
+pub fn calc_optical_flow_pyr_lk(
+    prev: &Mat,
+    next: &Mat,
+    prev_pts: &[Point2f],
+    next_pts: Option<&[Point2f]>,
+    win_size: Size,
+    max_level: i32,
+    criteria: TermCriteria,
+    flags: OpticalFlowFlags,
+    min_eig_threshold: f64,
+) -> Result<(Vec<Point2f>, Vec<u8>, Vec<f32>), Error> {
+    let mut prev_pts = prev_pts.to_vec();
+    let mut tracked = match next_pts {
+        Some(pts) if pts.len() != prev_pts.len() => {
+            return Err(CvError::DimensionMismatch {
+                expected: prev_pts.len() as i32,
+                actual: pts.len() as i32,
+            }
+            .into());
+        }
+        Some(pts) => pts.to_vec(),
+        None => prev_pts.clone(),
+    };
+    let mut status = vec![0; prev_pts.len()];
+    let mut err = vec![0.0; prev_pts.len()];
+    ::cv_result(unsafe {
+        ffi::CalcOpticalFlowPyrLKWithParams(
+            prev.inner,
+            next.inner,
+            to_points2f(&mut prev_pts),
+            to_points2f(&mut tracked),
+            status.as_mut_ptr(),
+            err.as_mut_ptr(),
+            win_size,
+            max_level,
+            criteria.into(),
+            flags.bits(),
+            min_eig_threshold,
+        )
+    })?;
+    Ok((tracked, status, err))
+}
+
+/// Computes a dense optical flow using the Gunnar Farneback's algorithm and
+/// returns it as a 2-channel floating-point Mat with the same size as `prev`.
+///
+/// * `prev`: first 8-bit single-channel input image.
+/// * `next`: second input image of the same size and the same type as `prev`.
+/// * `pyr_scale`: the image scale (<1) to build pyramids for each image;
+///   `pyr_scale` = 0.5 means a classical pyramid, where each next layer is
+///   twice smaller than the previous one.
+/// * `levels`: number of pyramid layers including the initial image.
+/// * `winsize`: averaging window size; larger values increase the algorithm
+///   robustness to image noise and give more chances for fast motion
+///   detection, but yield more blurred motion field.
+/// * `iterations`: number of iterations the algorithm does at each pyramid
+///   level.
+/// * `poly_n`: size of the pixel neighborhood used to find polynomial
+///   expansion in each pixel, typically 5 or 7.
+/// * `poly_sigma`: standard deviation of the Gaussian that is used to smooth
+///   derivatives used as a basis for the polynomial expansion; for `poly_n` =
+///   5, you can set `poly_sigma` = 1.1, for `poly_n` = 7, a good value would
+///   be `poly_sigma` = 1.5.
+pub fn calc_optical_flow_farneback(
+    prev: &Mat,
+    next: &Mat,
+    pyr_scale: f64,
+    levels: i32,
+    winsize: i32,
+    iterations: i32,
+    poly_n: i32,
+    poly_sigma: f64,
+    flags: OpticalFlowFlags,
+) -> Result<Mat, Error> {
+    let flow = Mat::new();
+    ::cv_result(unsafe {
+        ffi::CalcOpticalFlowFarneback_Checked(
+            prev.inner,
+            next.inner,
+            flow.inner,
+            pyr_scale,
+            levels,
+            winsize,
+            iterations,
+            poly_n,
+            poly_sigma,
+            flags.bits(),
+        )
+    })?;
+    Ok(flow)
+}
@@ -0,0 +1,425 @@
+//! [Video I/O](https://docs.opencv.org/master/dd/de7/group__videoio.html).
+
+use opencv_sys as ffi;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use core::{CvType, Depth, Mat, Size};
+use {CvError, Error};
+
+/// Properties of a [VideoCapture](struct.VideoCapture.html) that can be
+/// queried with `get` and changed with `set`. Not every property is supported
+/// by every backend; unsupported properties read back as `0`.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum CaptureProperty {
+    /// Current position of the video file in milliseconds.
+    PosMsec = 0,
+    /// 0-based index of the frame to be decoded/captured next.
+    PosFrames = 1,
+    /// Relative position of the video file: 0 = start of the film, 1 = end of
+    /// the film.
+    PosAviRatio = 2,
+    /// Width of the frames in the video stream.
+    FrameWidth = 3,
+    /// Height of the frames in the video stream.
+    FrameHeight = 4,
+    /// Frame rate.
+    Fps = 5,
+    /// 4-character code of codec.
+    FourCC = 6,
+    /// Number of frames in the video file.
+    FrameCount = 7,
+    /// Format of the Mat objects returned by `read`.
+    Format = 8,
+    /// Backend-specific value indicating the current capture mode.
+    Mode = 9,
+    /// Brightness of the image (only for cameras).
+    Brightness = 10,
+    /// Contrast of the image (only for cameras).
+    Contrast = 11,
+    /// Saturation of the image (only for cameras).
+    Saturation = 12,
+    /// Hue of the image (only for cameras).
+    Hue = 13,
+    /// Gain of the image (only for cameras).
+    Gain = 14,
+    /// Exposure (only for cameras).
+    Exposure = 15,
+    /// Boolean flags indicating whether images should be converted to RGB.
+    ConvertRgb = 16,
+    /// Rectification flag for stereo cameras.
+    Rectification = 18,
+    /// Monochrome flag.
+    Monochrome = 19,
+    /// Sharpness.
+    Sharpness = 20,
+    /// Auto exposure.
+    AutoExposure = 21,
+    /// Gamma.
+    Gamma = 22,
+    /// Temperature.
+    Temperature = 23,
+    /// Trigger.
+    Trigger = 24,
+    /// Trigger delay.
+    TriggerDelay = 25,
+    /// White balance (red channel).
+    WhiteBalanceRedV = 26,
+    /// Zoom.
+    Zoom = 27,
+    /// Focus.
+    Focus = 28,
+    /// Pixel format of the camera, backend specific.
+    Guid = 29,
+    /// ISO speed.
+    IsoSpeed = 30,
+    /// Backlight compensation.
+    Backlight = 32,
+    /// Pan.
+    Pan = 33,
+    /// Tilt.
+    Tilt = 34,
+    /// Roll.
+    Roll = 35,
+    /// Iris.
+    Iris = 36,
+    /// Pop up video/camera filter dialog.
+    Settings = 37,
+    /// Buffer size.
+    BufferSize = 38,
+    /// Auto focus.
+    AutoFocus = 39,
+}
+
+/// Class for video capturing from video files, image sequences or cameras.
+///
+/// A `VideoCapture` is also an iterator over the frames of its source, which
+/// ends when no more frames can be grabbed. If reading a frame fails, the
+/// error is yielded once and the capture is released, so the iterator ends
+/// after it.
+#[derive(Debug)]
+pub struct VideoCapture {
+    inner: ffi::VideoCapture,
+}
+
+impl Drop for VideoCapture {
+    fn drop(&mut self) {
+        unsafe { ffi::VideoCapture_Close(self.inner) }
+    }
+}
+
+impl VideoCapture {
+    /// Creates a capture that is not attached to any source yet.
+    pub fn new() -> Self {
+        VideoCapture {
+            inner: unsafe { ffi::VideoCapture_New() },
+        }
+    }
+
+    /// Opens a video file, an image sequence (e.g. `img_%02d.jpg`, which will
+    /// read samples like `img_00.jpg`, `img_01.jpg`, `img_02.jpg`, ...) or a
+    /// URL of a video stream.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        let uri = ::path_to_cstring(path)?;
+        let capture = VideoCapture::new();
+        let mut opened = false;
+        ::cv_result(unsafe {
+            ffi::VideoCapture_Open_Checked(capture.inner, uri.as_ptr(), &mut opened)
+        })?;
+        if opened {
+            Ok(capture)
+        } else {
+            Err(CvError::InvalidVideoSource(uri.to_string_lossy().into()).into())
+        }
+    }
+
+    /// Opens a camera for video capturing. To open the default camera, pass
+    /// `0`.
+    pub fn open_device(index: i32) -> Result<Self, Error> {
+        let capture = VideoCapture::new();
+        let mut opened = false;
+        ::cv_result(unsafe {
+            ffi::VideoCapture_OpenDevice_Checked(capture.inner, index, &mut opened)
+        })?;
+        if opened {
+            Ok(capture)
+        } else {
+            Err(CvError::InvalidVideoSource(format!("device {}", index)).into())
+        }
+    }
+
+    /// Returns true if video capturing has been initialized already.
+    pub fn is_opened(&self) -> bool {
+        unsafe { ffi::VideoCapture_IsOpened(self.inner) != 0 }
+    }
+
+    /// Closes video file or capturing device.
+    pub fn release(&mut self) {
+        unsafe { ffi::VideoCapture_Release(self.inner) }
+    }
+
+    /// Grabs, decodes and returns the next video frame into `mat`. Returns
+    /// false if no frames has been grabbed (camera has been disconnected, or
+    /// there are no more frames in video file).
+    pub fn read(&mut self, mat: &mut Mat) -> Result<bool, Error> {
+        let mut grabbed = false;
+        ::cv_result(unsafe {
+            ffi::VideoCapture_Read_Checked(self.inner, mat.inner, &mut grabbed)
+        })?;
+        Ok(grabbed)
+    }
+
+    /// Grabs and discards the next `skip` frames.
+    pub fn grab(&mut self, skip: i32) -> Result<(), Error> {
+        ::cv_result(unsafe { ffi::VideoCapture_Grab_Checked(self.inner, skip) })
+    }
+
+    /// Returns the specified property. Returns `0` when the property is not
+    /// supported by the backend.
+    pub fn get(&self, prop: CaptureProperty) -> f64 {
+        unsafe { ffi::VideoCapture_Get(self.inner, prop as i32) }
+    }
+
+    /// Sets a property.
+    pub fn set(&mut self, prop: CaptureProperty, value: f64) -> Result<(), Error> {
+        ::cv_result(unsafe { ffi::VideoCapture_Set_Checked(self.inner, prop as i32, value) })
+    }
+
+    /// Returns the 0-based index of the frame to be decoded next.
+    pub fn position(&self) -> i32 {
+        self.get(CaptureProperty::PosFrames) as i32
+    }
+
+    /// Seeks to the 0-based frame index.
+    pub fn set_position(&mut self, frame: i32) -> Result<(), Error> {
+        self.set(CaptureProperty::PosFrames, f64::from(frame))
+    }
+
+    /// Returns the current position in milliseconds.
+    pub fn position_msec(&self) -> f64 {
+        self.get(CaptureProperty::PosMsec)
+    }
+
+    /// Seeks to the position in milliseconds.
+    pub fn set_position_msec(&mut self, msec: f64) -> Result<(), Error> {
+        self.set(CaptureProperty::PosMsec, msec)
+    }
+
+    /// Returns the frame rate of the source.
+    pub fn fps(&self) -> f64 {
+        self.get(CaptureProperty::Fps)
+    }
+
+    /// Returns the number of frames in the source, if known.
+    pub fn frame_count(&self) -> i32 {
+        self.get(CaptureProperty::FrameCount) as i32
+    }
+
+    /// Returns the width of the frames.
+    pub fn frame_width(&self) -> i32 {
+        self.get(CaptureProperty::FrameWidth) as i32
+    }
+
+    /// Sets the width of the frames (only for cameras).
+    pub fn set_frame_width(&mut self, width: i32) -> Result<(), Error> {
+        self.set(CaptureProperty::FrameWidth, f64::from(width))
+    }
+
+    /// Returns the height of the frames.
+    pub fn frame_height(&self) -> i32 {
+        self.get(CaptureProperty::FrameHeight) as i32
+    }
+
+    /// Sets the height of the frames (only for cameras).
+    pub fn set_frame_height(&mut self, height: i32) -> Result<(), Error> {
+        self.set(CaptureProperty::FrameHeight, f64::from(height))
+    }
+}
+
+impl Iterator for VideoCapture {
+    type Item = Result<Mat, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.is_opened() {
+            return None;
+        }
+
+        let mut frame = Mat::new();
+        match self.read(&mut frame) {
+            Ok(true) if !frame.empty() => Some(Ok(frame)),
+            Ok(_) => None,
+            Err(e) => {
+                self.release();
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+/// Four character code of a video codec, as used by
+/// [VideoWriter](struct.VideoWriter.html).
+///
+/// The codec has to be supported by the container picked from the file
+/// extension, e.g. `MJPG` in an `.avi` file. A list of codes can be found at
+/// [fourcc.org](http://www.fourcc.org/codecs.php).
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct FourCC([u8; 4]);
+
+impl FourCC {
+    /// Motion JPEG.
+    pub const MJPG: FourCC = FourCC(*b"MJPG");
+    /// Xvid MPEG-4 Part 2.
+    pub const XVID: FourCC = FourCC(*b"XVID");
+    /// MPEG-4 Part 2.
+    pub const MP4V: FourCC = FourCC(*b"MP4V");
+    /// H.264 / MPEG-4 AVC.
+    pub const H264: FourCC = FourCC(*b"H264");
+    /// Uncompressed YUV 4:2:0 (raw video).
+    pub const I420: FourCC = FourCC(*b"I420");
+
+    /// Creates a code from four ASCII characters.
+    pub fn new(c1: u8, c2: u8, c3: u8, c4: u8) -> Result<Self, Error> {
+        let code = [c1, c2, c3, c4];
+        if code.iter().all(|c| c.is_ascii() && !c.is_ascii_control()) {
+            Ok(FourCC(code))
+        } else {
+            Err(CvError::InvalidFourCC(String::from_utf8_lossy(&code).into()).into())
+        }
+    }
+
+    /// Returns the code packed into an integer, as OpenCV expects it.
+    pub fn to_i32(self) -> i32 {
+        self.0
+            .iter()
+            .enumerate()
+            .fold(0, |acc, (i, &c)| acc | (i32::from(c) << (8 * i)))
+    }
+}
+
+impl FromStr for FourCC {
+    type Err = Error;
+
+    fn from_str(code: &str) -> Result<Self, Error> {
+        let bytes = code.as_bytes();
+        if bytes.len() != 4 {
+            return Err(CvError::InvalidFourCC(code.into()).into());
+        }
+        FourCC::new(bytes[0], bytes[1], bytes[2], bytes[3])
+    }
+}
+
+impl fmt::Display for FourCC {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", String::from_utf8_lossy(&self.0))
+    }
+}
+
+/// Video writer class.
+///
+/// The writer is opened with a fixed frame size and color mode; every frame
+/// passed to [write](struct.VideoWriter.html#method.write) has to match them.
+#[derive(Debug)]
+pub struct VideoWriter {
+    inner: ffi::VideoWriter,
+    size: Size,
+    is_color: bool,
+}
+
+impl Drop for VideoWriter {
+    fn drop(&mut self) {
+        unsafe { ffi::VideoWriter_Close(self.inner) }
+    }
+}
+
+impl VideoWriter {
+    /// Opens a video file for writing.
+    ///
+    /// * `path`: name of the output video file, its extension decides the
+    ///   container format.
+    /// * `fourcc`: codec used to compress the frames.
+    /// * `fps`: frame rate of the created video stream.
+    /// * `size`: size of the video frames.
+    /// * `is_color`: if true, the writer expects 3-channel BGR frames,
+    ///   otherwise single channel grayscale frames.
+    pub fn new<P: AsRef<Path>>(
+        path: P,
+        fourcc: FourCC,
+        fps: f64,
+        size: Size,
+        is_color: bool,
+    ) -> Result<Self, Error> {
+        let name = ::path_to_cstring(&path)?;
+        let writer = VideoWriter {
+            inner: unsafe { ffi::VideoWriter_New() },
+            size,
+            is_color,
+        };
+        let mut opened = false;
+        ::cv_result(unsafe {
+            ffi::VideoWriter_OpenWithFourCC(
+                writer.inner,
+                name.as_ptr(),
+                fourcc.to_i32(),
+                fps,
+                size,
+                is_color,
+                &mut opened,
+            )
+        })?;
+        if opened {
+            Ok(writer)
+        } else {
+            Err(CvError::InvalidVideoSink(PathBuf::from(path.as_ref())).into())
+        }
+    }
+
+    /// Returns true if video writer has been successfully initialized.
+    pub fn is_opened(&self) -> bool {
+        unsafe { ffi::VideoWriter_IsOpened(self.inner) != 0 }
+    }
+
+    /// Returns the frame size the writer was opened with.
+    pub fn size(&self) -> Size {
+        self.size
+    }
+
+    /// Returns true if the writer expects color frames.
+    pub fn is_color(&self) -> bool {
+        self.is_color
+    }
+
+    /// Writes the next video frame. The frame must have the size and the number
+    /// of channels the writer was opened with, and 8 bit unsigned elements.
+    pub fn write(&mut self, frame: &Mat) -> Result<(), Error> {
+        let actual = Size {
+            width: frame.cols(),
+            height: frame.rows(),
+        };
+        if actual.width != self.size.width || actual.height != self.size.height {
+            return Err(CvError::FrameSizeMismatch {
+                expected: self.size,
+                actual,
+            }
+            .into());
+        }
+
+        let channels = if self.is_color { 3 } else { 1 };
+        if frame.channels() != channels {
+            return Err(CvError::ChannelMismatch {
+                expected: channels,
+                actual: frame.channels(),
+            }
+            .into());
+        }
+        if frame.depth() != Depth::Cv8U {
+            return Err(CvError::TypeMismatch {
+                expected: CvType::new(Depth::Cv8U, channels),
+                actual: frame.cv_type(),
+            }
+            .into());
+        }
+
+        ::cv_result(unsafe { ffi::VideoWriter_Write_Checked(self.inner, frame.inner) })
+    }
+}
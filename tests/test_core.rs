@@ -1,5 +1,6 @@
 extern crate rustcv;
 use rustcv::core::*;
+use rustcv::CvError;
 
 #[test]
 fn mat_new() {
@@ -9,16 +10,70 @@ fn mat_new() {
 
 #[test]
 fn mat_with_size() {
-    let mat = Mat::new_with_size(101, 102, CvType::Cv8UC1);
+    let mat = Mat::new_with_size(101, 102, CvType::Cv8UC1).unwrap();
     assert_eq!(mat.rows(), 101);
     assert_eq!(mat.cols(), 102);
     assert_eq!(mat.channels(), 1);
-    assert_eq!(mat.cv_type() as i32, 0);
+    assert_eq!(mat.cv_type().to_i32(), 0);
+    assert_eq!(mat.depth(), Depth::Cv8U);
+    assert_eq!(mat.elem_size(), 1);
+}
+
+#[test]
+fn mat_with_negative_size() {
+    assert!(Mat::new_with_size(-1, 2, CvType::Cv8UC1).is_err());
+    assert!(TypedMat::<f32>::new(2, -1).is_err());
+}
+
+#[test]
+fn mat_four_channels() {
+    let mat = Mat::new_with_size(3, 4, CvType::Cv16UC4).unwrap();
+    assert_eq!(mat.cv_type(), CvType::Cv16UC4);
+    assert_eq!(mat.channels(), 4);
+    assert_eq!(mat.depth(), Depth::Cv16U);
+    assert_eq!(mat.elem_size(), 8);
+}
+
+#[test]
+fn cv_type() {
+    assert_eq!(CvType::Cv8UC3.to_i32(), 16);
+    assert_eq!(CvType::Cv64FC4.to_i32(), 30);
+    assert_eq!(CvType::from_i32(24), CvType::Cv8UC4);
+    assert_eq!(CvType::from(Depth::Cv32F), CvType::Cv32FC1);
+
+    let t = CvType::new(Depth::Cv16S, 7);
+    assert_eq!(t.to_i32(), 51);
+    assert_eq!(CvType::from_i32(t.to_i32()), t);
+    assert_eq!(t.elem_size(), 14);
+
+    for t in 0..(CvType::CN_MAX << 3) {
+        assert_eq!(CvType::from_i32(t).to_i32(), t);
+    }
+}
+
+#[test]
+#[should_panic]
+fn cv_type_invalid_channels() {
+    CvType::new(Depth::Cv8U, 0);
+}
+
+#[test]
+fn cv_type_try_new() {
+    assert_eq!(CvType::try_new(Depth::Cv32F, 2).unwrap(), CvType::Cv32FC2);
+    for &channels in &[0, -1, CvType::CN_MAX + 1] {
+        match CvType::try_new(Depth::Cv8U, channels)
+            .unwrap_err()
+            .downcast_ref::<CvError>()
+        {
+            Some(&CvError::InvalidChannels(n)) => assert_eq!(n, channels),
+            other => panic!("unexpected error: {:?}", other),
+        }
+    }
 }
 
 #[test]
 fn mat_clone() {
-    let mat = Mat::new_with_size(101, 102, CvType::Cv8UC1);
+    let mat = Mat::new_with_size(101, 102, CvType::Cv8UC1).unwrap();
     let clone = mat.clone();
     assert_eq!(clone.rows(), 101);
     assert_eq!(clone.cols(), 102);
@@ -26,30 +81,918 @@ fn mat_clone() {
 
 #[test]
 fn mat_copy_to() {
-    let mat = Mat::new_with_size(101, 102, CvType::Cv8UC1);
+    let mat = Mat::new_with_size(101, 102, CvType::Cv8UC1).unwrap();
     let mut dst = Mat::new();
-    mat.copy_to(&mut dst);
+    mat.copy_to(&mut dst).unwrap();
     assert_eq!(dst.rows(), 101);
     assert_eq!(dst.cols(), 102);
 }
 
 #[test]
 fn mat_copy_to_with_mask() {
-    let mut mat = Mat::new_with_size(101, 102, CvType::Cv8UC1);
-    let mut mask = Mat::new_with_size(101, 102, CvType::Cv8UC1);
+    let mut mat = Mat::new_with_size(101, 102, CvType::Cv8UC1).unwrap();
+    let mut mask = Mat::new_with_size(101, 102, CvType::Cv8UC1).unwrap();
     let mut diff = Mat::new();
 
-    mat.set_uchar_at(0, 0, 255);
-    mat.set_uchar_at(0, 1, 255);
-    mask.set_uchar_at(0, 0, 255);
+    mat.set_uchar_at(0, 0, 255).unwrap();
+    mat.set_uchar_at(0, 1, 255).unwrap();
+    mask.set_uchar_at(0, 0, 255).unwrap();
 
     let mut copy = Mat::new();
-    mat.copy_to_with_mask(&mut copy, &mask);
+    mat.copy_to_with_mask(&mut copy, &mask).unwrap();
     assert_eq!(copy.rows(), 101);
     assert_eq!(copy.cols(), 102);
-    assert_eq!(copy.uchar_at(0, 0), 255);
-    assert_eq!(copy.uchar_at(0, 1), 0);
+    assert_eq!(copy.uchar_at(0, 0).unwrap(), 255);
+    assert_eq!(copy.uchar_at(0, 1).unwrap(), 0);
+
+    compare(&mat, &copy, &mut diff, CompareType::Eq).unwrap();
+    assert_ne!(count_non_zero(&diff).unwrap(), 0);
+}
+
+#[test]
+fn term_criteria() {
+    let count = TermCriteria::max_iter(30);
+    assert_eq!(count.max_count(), Some(30));
+    assert_eq!(count.epsilon(), None);
+
+    let eps = TermCriteria::eps(0.01);
+    assert_eq!(eps.max_count(), None);
+    assert_eq!(eps.epsilon(), Some(0.01));
+
+    assert_eq!(count.with_eps(0.01), TermCriteria::new(30, 0.01));
+    assert_eq!(eps.with_max_iter(30), TermCriteria::new(30, 0.01));
+}
+
+#[test]
+fn mat_add() {
+    let a = Mat::new_from_scalar(
+        Scalar {
+            val1: 200.0,
+            val2: 0.0,
+            val3: 0.0,
+            val4: 0.0,
+        },
+        CvType::Cv8UC1,
+    )
+    .unwrap();
+    let b = Mat::new_from_scalar(
+        Scalar {
+            val1: 100.0,
+            val2: 0.0,
+            val3: 0.0,
+            val4: 0.0,
+        },
+        CvType::Cv8UC1,
+    )
+    .unwrap();
+    let mut dst = Mat::new();
+    add(&a, &b, &mut dst, None, None).unwrap();
+    assert_eq!(dst.uchar_at(0, 0).unwrap(), 255);
+}
+
+#[test]
+fn opencv_error() {
+    let a = Mat::new_with_size(10, 10, CvType::Cv8UC1).unwrap();
+    let b = Mat::new_with_size(20, 20, CvType::Cv8UC1).unwrap();
+    let mut dst = Mat::new();
+
+    let err = bitwise_and(&a, &b, &mut dst).unwrap_err();
+    match err.downcast_ref::<CvError>() {
+        Some(&CvError::OpenCv { code, ref func, .. }) => {
+            assert_ne!(code, 0);
+            assert!(!func.is_empty());
+        }
+        other => panic!("unexpected error: {:?}", other),
+    }
+}
+
+#[test]
+fn mat_to_points2f() {
+    let mut pairs = Mat::new_with_size(1, 2, CvType::Cv32FC2).unwrap();
+    for (i, v) in [1.0, 2.0, 3.0, 4.0].iter().enumerate() {
+        pairs.set_float_at(0, i as i32, *v).unwrap();
+    }
+    let points = pairs.to_points2f().unwrap();
+    assert_eq!(points.len(), 2);
+    assert_eq!((points[1].x, points[1].y), (3.0, 4.0));
+
+    let mut rows = Mat::new_with_size(2, 2, CvType::Cv32FC1).unwrap();
+    rows.set_float_at(1, 0, 5.0).unwrap();
+    rows.set_float_at(1, 1, 6.0).unwrap();
+    let points = rows.to_points2f().unwrap();
+    assert_eq!(points.len(), 2);
+    assert_eq!((points[1].x, points[1].y), (5.0, 6.0));
+
+    assert!(Mat::new().to_points2f().unwrap().is_empty());
+    let bytes = Mat::new_with_size(2, 2, CvType::Cv8UC2).unwrap();
+    match bytes.to_points2f().unwrap_err().downcast_ref::<CvError>() {
+        Some(&CvError::TypeMismatch { actual, .. }) => assert_eq!(actual, CvType::Cv8UC2),
+        other => panic!("unexpected error: {:?}", other),
+    }
+}
+
+#[test]
+fn mat_data() {
+    let mut mat = Mat::new_with_size(2, 3, CvType::Cv16UC1).unwrap();
+    assert!(mat.is_continuous());
+    assert_eq!(mat.data().map(|d| d.len()), Some(12));
+
+    for b in mat.data_mut().unwrap().iter_mut() {
+        *b = 1;
+    }
+    assert_eq!(mat.to_bytes(), vec![1; 12]);
+    assert_eq!(mat.data_row(1), Some(&[1u8; 6][..]));
+    assert_eq!(mat.data_row(2), None);
+
+    assert_eq!(Mat::new().data(), None);
+}
+
+#[test]
+fn mat_data_region() {
+    let mut mat = Mat::new_with_size(10, 10, CvType::Cv8UC1).unwrap();
+    for b in mat.data_mut().unwrap().iter_mut() {
+        *b = 0;
+    }
+    mat.set_uchar_at(3, 2, 7).unwrap();
+
+    let mut roi = mat
+        .region_mut(Rect {
+            x: 2,
+            y: 2,
+            width: 3,
+            height: 4,
+        })
+        .unwrap();
+    assert!(!roi.is_continuous());
+    assert_eq!(roi.data(), None);
+    assert_eq!(roi.cv_step(), 10);
+    assert_eq!(roi.data_row(1).unwrap()[0], 7);
+
+    roi.data_row_mut(3).unwrap().copy_from_slice(&[4, 5, 6]);
+    assert_eq!(mat.uchar_at(5, 2).unwrap(), 4);
+    assert_eq!(mat.uchar_at(5, 4).unwrap(), 6);
+    assert_eq!(mat.uchar_at(5, 5).unwrap(), 0);
+}
+
+#[test]
+fn typed_mat() {
+    let mut mat = TypedMat::<f64>::new(2, 2).unwrap();
+    *mat.at_mut(0, 0).unwrap() = 1.5;
+    *mat.at_mut(1, 1).unwrap() = -2.0;
+    assert_eq!(mat.at(0, 0), Some(&1.5));
+    assert_eq!(mat.double_at(1, 1).unwrap(), -2.0);
+    assert_eq!(mat.cv_type(), CvType::Cv64FC1);
+
+    let mat = TypedMat::<f64>::from_mat(mat.into_mat()).unwrap();
+    assert_eq!(mat.row(1).unwrap()[1], -2.0);
+    assert!(TypedMat::<f32>::from_mat(mat.into_mat()).is_err());
+}
+
+#[test]
+fn typed_mat_channels() {
+    let mut mat = TypedMat::<[u8; 3]>::new(2, 3).unwrap();
+    assert_eq!(mat.cv_type(), CvType::Cv8UC3);
+    *mat.at_mut(1, 2).unwrap() = [10, 20, 30];
+    assert_eq!(mat.at(1, 2), Some(&[10, 20, 30]));
+    assert_eq!(mat.data_row(1).unwrap()[6..], [10, 20, 30]);
+
+    let err = TypedMat::<[u16; 3]>::from_mat(Mat::from(mat)).unwrap_err();
+    match err.downcast_ref::<CvError>() {
+        Some(&CvError::TypeMismatch { expected, actual }) => {
+            assert_eq!(expected, CvType::Cv16UC3);
+            assert_eq!(actual, CvType::Cv8UC3);
+        }
+        other => panic!("unexpected error: {:?}", other),
+    }
+}
+
+#[test]
+fn typed_mat_out_of_range() {
+    let mut mat = TypedMat::<i32>::new(2, 2).unwrap();
+    assert_eq!(mat.at(1, 1), Some(&0));
+    assert_eq!(mat.at(0, 2), None);
+    assert_eq!(mat.at(0, -1), None);
+    assert!(mat.row(2).is_none());
+    assert!(mat.at_mut(2, 0).is_none());
+}
+
+#[test]
+fn checked_accessors() {
+    let mut mat = Mat::new_with_size(2, 3, CvType::Cv8UC3).unwrap();
+    mat.set_uchar_at(1, 8, 42).unwrap();
+    assert_eq!(mat.uchar_at(1, 8).unwrap(), 42);
+
+    let err = mat.uchar_at(1, 9).unwrap_err();
+    match err.downcast_ref::<CvError>() {
+        Some(&CvError::OutOfBounds {
+            ref index,
+            ref shape,
+        }) => {
+            assert_eq!(index, &vec![1, 9]);
+            assert_eq!(shape, &vec![2, 9]);
+        }
+        other => panic!("unexpected error: {:?}", other),
+    }
+    assert!(mat.set_uchar_at(-1, 0, 0).is_err());
+    assert!(mat.uchar_at3(0, 0, 0).is_err());
+
+    let err = mat.float_at(0, 0).unwrap_err();
+    match err.downcast_ref::<CvError>() {
+        Some(&CvError::TypeMismatch { expected, actual }) => {
+            assert_eq!(expected, CvType::Cv32FC3);
+            assert_eq!(actual, CvType::Cv8UC3);
+        }
+        other => panic!("unexpected error: {:?}", other),
+    }
+    assert!(mat.set_double_at(0, 0, 1.0).is_err());
+}
+
+fn scalar(v: f64) -> Scalar {
+    Scalar {
+        val1: v,
+        val2: v,
+        val3: v,
+        val4: v,
+    }
+}
+
+#[test]
+fn mat_operators() {
+    let a = Mat::new_from_scalar(scalar(200.0), CvType::Cv8UC1).unwrap();
+    let b = Mat::new_from_scalar(scalar(100.0), CvType::Cv8UC1).unwrap();
+
+    assert_eq!((&a + &b).uchar_at(0, 0).unwrap(), 255);
+    assert_eq!((&b - &a).uchar_at(0, 0).unwrap(), 0);
+    assert_eq!((&a - &b).uchar_at(0, 0).unwrap(), 100);
+    assert_eq!((&a * &b).uchar_at(0, 0).unwrap(), 255);
+    assert_eq!((&a / &b).uchar_at(0, 0).unwrap(), 2);
+    assert_eq!((&a + scalar(10.0)).uchar_at(0, 0).unwrap(), 210);
+    assert_eq!((&b * 0.5).uchar_at(0, 0).unwrap(), 50);
+    assert_eq!((&b / 3.0).uchar_at(0, 0).unwrap(), 33);
+    assert_eq!((&b / 0.0).uchar_at(0, 0).unwrap(), 0);
+    assert_eq!((&b / scalar(0.0)).uchar_at(0, 0).unwrap(), 0);
+    assert_eq!((-&b).uchar_at(0, 0).unwrap(), 0);
+    assert_eq!((a.clone() - b.clone()).uchar_at(0, 0).unwrap(), 100);
+
+    assert_eq!((&a & &b).uchar_at(0, 0).unwrap(), 200 & 100);
+    assert_eq!((&a | &b).uchar_at(0, 0).unwrap(), 200 | 100);
+    assert_eq!((&a ^ scalar(255.0)).uchar_at(0, 0).unwrap(), 55);
+    assert_eq!((!&a).uchar_at(0, 0).unwrap(), 55);
+
+    let f = Mat::new_from_scalar(scalar(1.5), CvType::Cv32FC1).unwrap();
+    assert_eq!((-f).float_at(0, 0).unwrap(), -1.5);
+}
+
+#[test]
+fn mat_assign_operators() {
+    let mut mat = Mat::new_from_scalar(scalar(10.0), CvType::Cv16SC1).unwrap();
+    let other = Mat::new_from_scalar(scalar(3.0), CvType::Cv16SC1).unwrap();
+
+    mat += &other;
+    assert_eq!(mat.short_at(0, 0).unwrap(), 13);
+    mat -= scalar(20.0);
+    assert_eq!(mat.short_at(0, 0).unwrap(), -7);
+    mat *= 2.0;
+    assert_eq!(mat.short_at(0, 0).unwrap(), -14);
+    mat /= other.clone();
+    assert_eq!(mat.short_at(0, 0).unwrap(), -5);
+    mat |= scalar(1.0);
+    assert_eq!(mat.short_at(0, 0).unwrap(), -5 | 1);
+}
+
+#[test]
+#[should_panic]
+fn mat_operator_size_mismatch() {
+    let a = Mat::new_with_size(10, 10, CvType::Cv8UC1).unwrap();
+    let b = Mat::new_with_size(20, 20, CvType::Cv8UC1).unwrap();
+    let _ = &a + &b;
+}
+
+#[test]
+fn per_element_arithmetic() {
+    let a = Mat::new_from_scalar(scalar(200.0), CvType::Cv8UC1).unwrap();
+    let b = Mat::new_from_scalar(scalar(50.0), CvType::Cv8UC1).unwrap();
+    let mut dst = Mat::new();
+
+    subtract(&b, &a, &mut dst, None, Some(Depth::Cv16S)).unwrap();
+    assert_eq!(dst.cv_type(), CvType::Cv16SC1);
+    assert_eq!(dst.short_at(0, 0).unwrap(), -150);
+
+    let mut src = Mat::new_with_size(1, 2, CvType::Cv8UC1).unwrap();
+    src.data_mut().unwrap().copy_from_slice(&[10, 20]);
+    let mut mask = Mat::new_with_size(1, 2, CvType::Cv8UC1).unwrap();
+    mask.data_mut().unwrap().copy_from_slice(&[1, 0]);
+    let mut masked = Mat::new_with_size(1, 2, CvType::Cv8UC1).unwrap();
+    masked.data_mut().unwrap().copy_from_slice(&[7, 7]);
+    subtract(&src, &src, &mut masked, Some(&mask), None).unwrap();
+    assert_eq!(masked.data(), Some(&[0, 7][..]));
+    add(&src, &src, &mut masked, Some(&mask), None).unwrap();
+    assert_eq!(masked.data(), Some(&[20, 7][..]));
+
+    add(&a, &a, &mut dst, None, Some(Depth::Cv16S)).unwrap();
+    assert_eq!(dst.cv_type(), CvType::Cv16SC1);
+    assert_eq!(dst.short_at(0, 0).unwrap(), 400);
+
+    multiply(&a, &b, &mut dst, 0.01, Some(Depth::Cv32F)).unwrap();
+    assert_eq!(dst.float_at(0, 0).unwrap(), 100.0);
+    divide(&a, &b, &mut dst, 2.0, None).unwrap();
+    assert_eq!(dst.uchar_at(0, 0).unwrap(), 8);
+    scale_add(&b, 2.0, &b, &mut dst).unwrap();
+    assert_eq!(dst.uchar_at(0, 0).unwrap(), 150);
+
+    min(&a, &b, &mut dst).unwrap();
+    assert_eq!(dst.uchar_at(0, 0).unwrap(), 50);
+    max(&a, &b, &mut dst).unwrap();
+    assert_eq!(dst.uchar_at(0, 0).unwrap(), 200);
+}
+
+#[test]
+fn math_functions() {
+    let src = Mat::new_from_scalar(scalar(4.0), CvType::Cv32FC1).unwrap();
+    let mut dst = Mat::new();
+
+    pow(&src, 1.5, &mut dst).unwrap();
+    assert!((dst.float_at(0, 0).unwrap() - 8.0).abs() < 1e-3);
+    sqrt(&src, &mut dst).unwrap();
+    assert_eq!(dst.float_at(0, 0).unwrap(), 2.0);
+    exp(&src, &mut dst).unwrap();
+    assert!((dst.float_at(0, 0).unwrap() - 4f32.exp()).abs() < 1e-3);
+    log(&src, &mut dst).unwrap();
+    assert!((dst.float_at(0, 0).unwrap() - 4f32.ln()).abs() < 1e-3);
+
+    let neg = Mat::new_from_scalar(scalar(-3.0), CvType::Cv32FC1).unwrap();
+    abs(&neg, &mut dst).unwrap();
+    assert_eq!(dst.float_at(0, 0).unwrap(), 3.0);
+}
+
+#[test]
+fn polar_coordinates() {
+    let x = Mat::new_from_scalar(scalar(3.0), CvType::Cv32FC1).unwrap();
+    let y = Mat::new_from_scalar(scalar(4.0), CvType::Cv32FC1).unwrap();
+    let mut mag = Mat::new();
+    let mut angle = Mat::new();
+
+    magnitude(&x, &y, &mut mag).unwrap();
+    assert_eq!(mag.float_at(0, 0).unwrap(), 5.0);
+    phase(&x, &x, &mut angle, true).unwrap();
+    assert!((angle.float_at(0, 0).unwrap() - 45.0).abs() < 0.1);
+
+    phase(&x, &y, &mut angle, false).unwrap();
+    let mut x2 = Mat::new();
+    let mut y2 = Mat::new();
+    polar_to_cart(&mag, &angle, &mut x2, &mut y2, false).unwrap();
+    assert!((x2.float_at(0, 0).unwrap() - 3.0).abs() < 1e-3);
+    assert!((y2.float_at(0, 0).unwrap() - 4.0).abs() < 1e-3);
+}
+
+fn line_samples() -> Mat {
+    let mut data = TypedMat::<f64>::new(4, 2).unwrap();
+    for i in 0..4 {
+        let x = f64::from(i + 1);
+        data.row_mut(i).unwrap().copy_from_slice(&[x, 2.0 * x]);
+    }
+    data.into_mat()
+}
+
+#[test]
+fn pca() {
+    let data = line_samples();
+    let pca = Pca::new(&data, PcaLayout::Rows, None, PcaRetain::Components(0)).unwrap();
+    assert_eq!(pca.eigenvalues().rows(), 2);
+    assert_eq!(pca.mean().double_at(0, 0).unwrap(), 2.5);
+    assert_eq!(pca.mean().double_at(0, 1).unwrap(), 5.0);
+
+    let pca = Pca::new(&data, PcaLayout::Rows, None, PcaRetain::Variance(0.99)).unwrap();
+    assert_eq!(pca.eigenvectors().rows(), 1);
+    assert_eq!(pca.eigenvectors().cols(), 2);
+
+    let projected = pca.project(&data).unwrap();
+    assert_eq!((projected.rows(), projected.cols()), (4, 1));
+    let restored = pca.back_project(&projected).unwrap();
+    for r in 0..4 {
+        for c in 0..2 {
+            let diff = restored.double_at(r, c).unwrap() - data.double_at(r, c).unwrap();
+            assert!(diff.abs() < 1e-9);
+        }
+    }
+}
 
-    compare(&mat, &copy, &mut diff, CompareType::Eq);
-    assert_ne!(count_non_zero(&diff), 0);
+#[test]
+fn pca_cols_with_mean() {
+    let mut data = Mat::new();
+    rustcv::core::linalg::transpose(&line_samples(), &mut data).unwrap();
+    let mean = TypedMat::<f64>::new(2, 1).unwrap();
+
+    let pca = Pca::new(
+        &data,
+        PcaLayout::Cols,
+        Some(&mean),
+        PcaRetain::Components(1),
+    )
+    .unwrap();
+    assert_eq!(pca.mean().double_at(1, 0).unwrap(), 0.0);
+    assert_eq!(pca.project(&data).unwrap().cols(), 4);
+}
+
+#[test]
+fn pca_mismatched_mean() {
+    let mean = TypedMat::<f64>::new(1, 3).unwrap();
+    assert!(Pca::new(
+        &line_samples(),
+        PcaLayout::Rows,
+        Some(&mean),
+        PcaRetain::Components(0),
+    )
+    .is_err());
+}
+
+#[test]
+fn dft_roundtrip() {
+    assert_eq!(get_optimal_dft_size(7), 8);
+    assert_eq!(get_optimal_dft_size(97), 100);
+
+    let mut src = TypedMat::<f32>::new(1, 4).unwrap();
+    src.row_mut(0)
+        .unwrap()
+        .copy_from_slice(&[1.0, 2.0, 3.0, 4.0]);
+    let src = src.into_mat();
+
+    let mut spectrum = Mat::new();
+    dft(&src, &mut spectrum, DftFlags::COMPLEX_OUTPUT, 0).unwrap();
+    assert_eq!(spectrum.cv_type(), CvType::Cv32FC2);
+    assert_eq!(spectrum.float_at(0, 0).unwrap(), 10.0);
+    assert_eq!(spectrum.float_at(0, 1).unwrap(), 0.0);
+
+    let mut power = Mat::new();
+    mul_spectrums(&spectrum, &spectrum, &mut power, DftFlags::empty(), true).unwrap();
+    assert_eq!(power.float_at(0, 0).unwrap(), 100.0);
+
+    let mut restored = Mat::new();
+    idft(
+        &spectrum,
+        &mut restored,
+        DftFlags::SCALE | DftFlags::REAL_OUTPUT,
+        0,
+    )
+    .unwrap();
+    assert_eq!(restored.cv_type(), CvType::Cv32FC1);
+    for c in 0..4 {
+        let diff = restored.float_at(0, c).unwrap() - src.float_at(0, c).unwrap();
+        assert!(diff.abs() < 1e-5);
+    }
+}
+
+#[test]
+fn dct_roundtrip() {
+    let mut src = TypedMat::<f64>::new(2, 2).unwrap();
+    src.row_mut(0).unwrap().copy_from_slice(&[1.0, 1.0]);
+    src.row_mut(1).unwrap().copy_from_slice(&[1.0, 1.0]);
+    let src = src.into_mat();
+
+    let mut coeffs = Mat::new();
+    dct(&src, &mut coeffs, DftFlags::empty()).unwrap();
+    assert!((coeffs.double_at(0, 0).unwrap() - 2.0).abs() < 1e-9);
+    assert!(coeffs.double_at(1, 1).unwrap().abs() < 1e-9);
+
+    let mut restored = Mat::new();
+    idct(&coeffs, &mut restored, DftFlags::empty()).unwrap();
+    assert!((restored.double_at(1, 0).unwrap() - 1.0).abs() < 1e-9);
+}
+
+fn bgr_pixels() -> Mat {
+    let mut mat = TypedMat::<[u8; 3]>::new(2, 2).unwrap();
+    for r in 0..2 {
+        for c in 0..2 {
+            *mat.at_mut(r, c).unwrap() = [1, 2, 3];
+        }
+    }
+    mat.into_mat()
+}
+
+#[test]
+fn split_and_merge() {
+    let channels = split(&bgr_pixels()).unwrap();
+    assert_eq!(channels.len(), 3);
+    assert_eq!(channels[2].cv_type(), CvType::Cv8UC1);
+    assert_eq!(channels[2].uchar_at(1, 1).unwrap(), 3);
+
+    let alpha = Mat::new_with_size(2, 2, CvType::Cv8UC1).unwrap();
+    let mut bgra = channels;
+    bgra.push(alpha);
+    let merged = merge(&bgra).unwrap();
+    assert_eq!(merged.cv_type(), CvType::Cv8UC4);
+    assert_eq!(merged.data_row(0).unwrap()[..3], [1, 2, 3]);
+
+    assert!(merge(&[
+        Mat::new_with_size(1, 1, CvType::Cv8UC1).unwrap(),
+        Mat::new_with_size(2, 2, CvType::Cv8UC1).unwrap()
+    ])
+    .is_err());
+}
+
+#[test]
+fn mix_extract_insert_channels() {
+    let src = bgr_pixels();
+
+    let mut red = Mat::new();
+    extract_channel(&src, &mut red, 2).unwrap();
+    assert_eq!(red.uchar_at(0, 1).unwrap(), 3);
+
+    let mut dst = src.clone();
+    insert_channel(&red, &mut dst, 0).unwrap();
+    assert_eq!(dst.data_row(1).unwrap()[..3], [3, 2, 3]);
+
+    let mut out = [
+        Mat::new_with_size(2, 2, CvType::Cv8UC3).unwrap(),
+        Mat::new_with_size(2, 2, CvType::Cv8UC1).unwrap(),
+    ];
+    mix_channels(&[src], &mut out, &[(0, 2), (1, 1), (2, 0), (-1, 3)]).unwrap();
+    assert_eq!(out[0].data_row(0).unwrap()[..3], [3, 2, 1]);
+    assert_eq!(out[1].uchar_at(0, 0).unwrap(), 0);
+}
+
+fn counting(rows: i32, cols: i32) -> Mat {
+    let mut mat = Mat::new_with_size(rows, cols, CvType::Cv8UC1).unwrap();
+    for (i, b) in mat.data_mut().unwrap().iter_mut().enumerate() {
+        *b = i as u8;
+    }
+    mat
+}
+
+#[test]
+fn flip_and_rotate() {
+    let src = counting(2, 3);
+    let mut dst = Mat::new();
+
+    flip(&src, &mut dst, FlipCode::Horizontal).unwrap();
+    assert_eq!(dst.data(), Some(&[2, 1, 0, 5, 4, 3][..]));
+    flip(&src, &mut dst, FlipCode::Vertical).unwrap();
+    assert_eq!(dst.data(), Some(&[3, 4, 5, 0, 1, 2][..]));
+    flip(&src, &mut dst, FlipCode::Both).unwrap();
+    assert_eq!(dst.data(), Some(&[5, 4, 3, 2, 1, 0][..]));
+
+    rotate(&src, &mut dst, RotateCode::Rotate90).unwrap();
+    assert_eq!((dst.rows(), dst.cols()), (3, 2));
+    assert_eq!(dst.data(), Some(&[3, 0, 4, 1, 5, 2][..]));
+    rotate(&src, &mut dst, RotateCode::Rotate180).unwrap();
+    assert_eq!(dst.data(), Some(&[5, 4, 3, 2, 1, 0][..]));
+    rotate(&src, &mut dst, RotateCode::Rotate270).unwrap();
+    assert_eq!(dst.data(), Some(&[2, 5, 1, 4, 0, 3][..]));
+
+    transpose(&src, &mut dst).unwrap();
+    assert_eq!(dst.data(), Some(&[0, 3, 1, 4, 2, 5][..]));
+}
+
+#[test]
+fn repeat_and_concat() {
+    let src = counting(1, 2);
+    let mut dst = Mat::new();
+    repeat(&src, 2, 2, &mut dst).unwrap();
+    assert_eq!(dst.data(), Some(&[0, 1, 0, 1, 0, 1, 0, 1][..]));
+
+    let side_by_side = hconcat(&[src.clone(), counting(1, 3)]).unwrap();
+    assert_eq!(side_by_side.data(), Some(&[0, 1, 0, 1, 2][..]));
+
+    let stacked = vconcat(&[src.clone(), src.clone()]).unwrap();
+    assert_eq!((stacked.rows(), stacked.cols()), (2, 2));
+
+    assert!(vconcat(&[src, counting(1, 3)]).is_err());
+}
+
+fn half_mask() -> Mat {
+    let mut mask = Mat::new_with_size(2, 3, CvType::Cv8UC1).unwrap();
+    mask.data_mut()
+        .unwrap()
+        .copy_from_slice(&[255, 255, 255, 0, 0, 0]);
+    mask
+}
+
+#[test]
+fn masked_statistics() {
+    let src = counting(2, 3);
+    let mask = half_mask();
+
+    assert_eq!(src.mean().unwrap().val1, 2.5);
+    assert_eq!(src.mean_with_mask(&mask).unwrap().val1, 1.0);
+
+    let (mean, stddev) = mean_std_dev(&src, Some(&mask)).unwrap();
+    assert_eq!(mean.val1, 1.0);
+    assert!((stddev.val1 - (2.0f64 / 3.0).sqrt()).abs() < 1e-9);
+    let (mean, _) = mean_std_dev(&src, None).unwrap();
+    assert_eq!(mean.val1, 2.5);
+
+    let (min, max, min_loc, max_loc) = min_max_loc_with_mask(&src, &mask).unwrap();
+    assert_eq!((min, max), (0.0, 2.0));
+    assert_eq!((min_loc.x, min_loc.y), (0, 0));
+    assert_eq!((max_loc.x, max_loc.y), (2, 0));
+
+    let (min, max, min_idx, max_idx) = min_max_idx(&src, None).unwrap();
+    assert_eq!((min, max), (0.0, 5.0));
+    assert_eq!(min_idx, vec![0, 0]);
+    assert_eq!(max_idx, vec![1, 2]);
+
+    let (_, max, _, max_idx) = min_max_idx(&src, Some(&mask)).unwrap();
+    assert_eq!(max, 2.0);
+    assert_eq!(max_idx, vec![0, 2]);
+
+    let (min, max, min_idx, _) = min_max_idx(&bgr_pixels(), None).unwrap();
+    assert_eq!((min, max), (1.0, 3.0));
+    assert!(min_idx.is_empty());
+}
+
+#[test]
+fn norms() {
+    let src = counting(2, 3);
+    let zero = Mat::new_from_scalar(scalar(0.0), CvType::Cv8UC1).unwrap();
+    let mask = half_mask();
+
+    assert_eq!(norm(&src, NormType::L1, None).unwrap(), 15.0);
+    assert_eq!(norm(&src, NormType::Inf, None).unwrap(), 5.0);
+    assert_eq!(norm(&src, NormType::L2Sqr, None).unwrap(), 55.0);
+    assert_eq!(norm(&src, NormType::L2, Some(&mask)).unwrap(), 5f64.sqrt());
+    assert_eq!(norm(&zero, NormType::Hamming, None).unwrap(), 0.0);
+
+    let mut other = src.clone();
+    other.set_uchar_at(1, 2, 7).unwrap();
+    assert_eq!(
+        norm_diff(&other, &src, NormType::L1, false, None).unwrap(),
+        2.0
+    );
+    assert_eq!(
+        norm_diff(&other, &src, NormType::L1, true, None).unwrap(),
+        2.0 / 15.0
+    );
+    assert_eq!(
+        norm_diff(&other, &src, NormType::Inf, false, Some(&mask)).unwrap(),
+        0.0
+    );
+    assert_eq!(
+        norm_diff(&other, &src, NormType::Hamming, false, None).unwrap(),
+        1.0
+    );
+}
+
+#[test]
+fn in_range_mask() {
+    let mut hsv = TypedMat::<[u8; 3]>::new(1, 3).unwrap();
+    hsv.row_mut(0)
+        .unwrap()
+        .copy_from_slice(&[[10, 200, 200], [100, 200, 200], [15, 20, 200]]);
+
+    let lower = Scalar {
+        val1: 5.0,
+        val2: 100.0,
+        val3: 100.0,
+        val4: 0.0,
+    };
+    let upper = Scalar {
+        val1: 20.0,
+        val2: 255.0,
+        val3: 255.0,
+        val4: 0.0,
+    };
+    let mut mask = Mat::new();
+    in_range(&hsv, lower, upper, &mut mask).unwrap();
+    assert_eq!(mask.cv_type(), CvType::Cv8UC1);
+    assert_eq!(mask.data(), Some(&[255, 0, 0][..]));
+}
+
+#[test]
+fn normalize_values() {
+    let src = counting(2, 3);
+    let mut dst = Mat::new();
+
+    normalize(
+        &src,
+        &mut dst,
+        0.0,
+        1.0,
+        NormalizeType::MinMax,
+        Some(Depth::Cv32F),
+        None,
+    )
+    .unwrap();
+    assert_eq!(dst.cv_type(), CvType::Cv32FC1);
+    assert_eq!(dst.float_at(0, 0).unwrap(), 0.0);
+    assert_eq!(dst.float_at(1, 2).unwrap(), 1.0);
+    assert_eq!(dst.float_at(0, 1).unwrap(), 0.2);
+
+    normalize(
+        &src,
+        &mut dst,
+        1.0,
+        0.0,
+        NormalizeType::L1,
+        Some(Depth::Cv64F),
+        None,
+    )
+    .unwrap();
+    assert!((dst.double_at(1, 2).unwrap() - 5.0 / 15.0).abs() < 1e-9);
+
+    let mut masked = Mat::new_with_size(2, 3, CvType::Cv8UC1).unwrap();
+    normalize(
+        &src,
+        &mut masked,
+        0.0,
+        100.0,
+        NormalizeType::MinMax,
+        None,
+        Some(&half_mask()),
+    )
+    .unwrap();
+    assert_eq!(masked.uchar_at(0, 0).unwrap(), 0);
+    assert_eq!(masked.uchar_at(0, 2).unwrap(), 100);
+}
+
+#[test]
+fn reduce_rows_and_columns() {
+    let src = counting(2, 3);
+    let mut dst = Mat::new();
+
+    reduce(
+        &src,
+        &mut dst,
+        ReduceDimension::ToRow,
+        ReduceType::Sum,
+        Some(Depth::Cv32S),
+    )
+    .unwrap();
+    assert_eq!((dst.rows(), dst.cols()), (1, 3));
+    assert_eq!(dst.int_at(0, 2).unwrap(), 7);
+
+    reduce(
+        &src,
+        &mut dst,
+        ReduceDimension::ToColumn,
+        ReduceType::Avg,
+        Some(Depth::Cv32F),
+    )
+    .unwrap();
+    assert_eq!((dst.rows(), dst.cols()), (2, 1));
+    assert_eq!(dst.float_at(1, 0).unwrap(), 4.0);
+
+    reduce(
+        &src,
+        &mut dst,
+        ReduceDimension::ToColumn,
+        ReduceType::Max,
+        None,
+    )
+    .unwrap();
+    assert_eq!(dst.uchar_at(0, 0).unwrap(), 2);
+    reduce(
+        &src,
+        &mut dst,
+        ReduceDimension::ToRow,
+        ReduceType::Min,
+        None,
+    )
+    .unwrap();
+    assert_eq!(dst.data(), Some(&[0, 1, 2][..]));
+}
+
+#[test]
+fn seeded_rng() {
+    let mut a = Rng::new(42);
+    let mut b = Rng::new(42);
+    assert_eq!(a.next_u32(), b.next_u32());
+    let v = a.uniform_i32(10, 20);
+    assert_eq!(v, b.uniform_i32(10, 20));
+    assert!((10..20).contains(&v));
+    let x = a.uniform_f64(0.0, 1.0);
+    assert_eq!(x, b.uniform_f64(0.0, 1.0));
+    assert!((0.0..1.0).contains(&x));
+    assert_eq!(a.gaussian(2.0), b.gaussian(2.0));
+
+    let mut noise_a = Mat::new_with_size(4, 5, CvType::Cv8UC3).unwrap();
+    let mut noise_b = Mat::new_with_size(4, 5, CvType::Cv8UC3).unwrap();
+    a.fill_uniform(&mut noise_a, scalar(50.0), scalar(100.0))
+        .unwrap();
+    b.fill_uniform(&mut noise_b, scalar(50.0), scalar(100.0))
+        .unwrap();
+    assert_eq!(noise_a.data(), noise_b.data());
+    let noise = noise_a.data().unwrap();
+    assert!(noise.iter().all(|v| (50..100).contains(v)));
+
+    let mut gauss_a = Mat::new_with_size(3, 3, CvType::Cv32FC1).unwrap();
+    let mut gauss_b = Mat::new_with_size(3, 3, CvType::Cv32FC1).unwrap();
+    a.fill_normal(&mut gauss_a, scalar(0.0), scalar(1.0))
+        .unwrap();
+    b.fill_normal(&mut gauss_b, scalar(0.0), scalar(1.0))
+        .unwrap();
+    assert_eq!(gauss_a.data(), gauss_b.data());
+}
+
+#[test]
+fn mat_randu_randn() {
+    set_rng_seed(7);
+    let mut first = Mat::new_with_size(8, 8, CvType::Cv16SC1).unwrap();
+    first.randu(scalar(-5.0), scalar(5.0)).unwrap();
+    set_rng_seed(7);
+    let mut second = Mat::new_with_size(8, 8, CvType::Cv16SC1).unwrap();
+    second.randu(scalar(-5.0), scalar(5.0)).unwrap();
+    assert_eq!(first.data(), second.data());
+    let (min, max, _, _) = min_max_loc(&first).unwrap();
+    assert!(min >= -5.0 && max < 5.0);
+
+    let mut normal = Mat::new_with_size(100, 100, CvType::Cv64FC1).unwrap();
+    normal.randn(scalar(10.0), scalar(0.0)).unwrap();
+    assert_eq!(normal.double_at(50, 50).unwrap(), 10.0);
+    normal.randn(scalar(10.0), scalar(2.0)).unwrap();
+    let (mean, stddev) = mean_std_dev(&normal, None).unwrap();
+    assert!((mean.val1 - 10.0).abs() < 0.1);
+    assert!((stddev.val1 - 2.0).abs() < 0.1);
+}
+
+#[test]
+fn mat_rand_shuffle() {
+    let mut a = counting(1, 10);
+    let mut b = counting(1, 10);
+    a.rand_shuffle(1.0, Some(&mut Rng::new(3))).unwrap();
+    b.rand_shuffle(1.0, Some(&mut Rng::new(3))).unwrap();
+    assert_eq!(a.data(), b.data());
+
+    let mut values = a.data().unwrap().to_vec();
+    values.sort();
+    assert_eq!(values, (0..10).collect::<Vec<u8>>());
+
+    a.rand_shuffle(2.0, None).unwrap();
+    let mut values = a.data().unwrap().to_vec();
+    values.sort();
+    assert_eq!(values, (0..10).collect::<Vec<u8>>());
+}
+
+/// Six 2D points in two well separated groups.
+fn two_clusters() -> TypedMat<f32> {
+    let points = [
+        (0.0, 0.0),
+        (1.0, 0.0),
+        (0.0, 1.0),
+        (10.0, 10.0),
+        (11.0, 10.0),
+        (10.0, 11.0),
+    ];
+    let mut data = TypedMat::<f32>::new(points.len() as i32, 2).unwrap();
+    for (i, &(x, y)) in points.iter().enumerate() {
+        *data.at_mut(i as i32, 0).unwrap() = x;
+        *data.at_mut(i as i32, 1).unwrap() = y;
+    }
+    data
+}
+
+#[test]
+fn kmeans_clusters() {
+    let data = two_clusters();
+    let criteria = TermCriteria::new(10, 1e-3);
+    for &flags in &[KmeansFlags::RandomCenters, KmeansFlags::PpCenters] {
+        let (compactness, labels, centers) = kmeans(&data, 2, criteria, 3, flags).unwrap();
+        assert_eq!((labels.rows(), labels.cols()), (6, 1));
+        assert_eq!(labels.cv_type(), CvType::Cv32SC1);
+        assert_eq!((centers.rows(), centers.cols()), (2, 2));
+        assert!((compactness - 8.0 / 3.0).abs() < 1e-3);
+
+        let first = labels.int_at(0, 0).unwrap();
+        let second = labels.int_at(3, 0).unwrap();
+        assert_ne!(first, second);
+        for i in 0..3 {
+            assert_eq!(labels.int_at(i, 0).unwrap(), first);
+            assert_eq!(labels.int_at(i + 3, 0).unwrap(), second);
+        }
+        let center = centers.float_at(second, 0).unwrap();
+        assert!((center - 31.0 / 3.0).abs() < 1e-4);
+    }
+}
+
+#[test]
+fn kmeans_initial_labels() {
+    let data = two_clusters();
+    let mut initial = TypedMat::<i32>::new(6, 1).unwrap();
+    for i in 0..6 {
+        *initial.at_mut(i, 0).unwrap() = if i < 3 { 1 } else { 0 };
+    }
+    let (_, labels, centers) = kmeans(
+        &data,
+        2,
+        TermCriteria::max_iter(10),
+        1,
+        KmeansFlags::UseInitialLabels(&initial),
+    )
+    .unwrap();
+    assert_eq!(labels.int_at(0, 0).unwrap(), 1);
+    assert_eq!(labels.int_at(5, 0).unwrap(), 0);
+    assert!(centers.float_at(1, 0).unwrap() < 1.0);
+    assert_eq!(initial.int_at(0, 0).unwrap(), 1);
+}
+
+#[test]
+fn kmeans_wrong_depth() {
+    let data = Mat::new_from_scalar(scalar(1.0), CvType::Cv8UC1).unwrap();
+    assert!(kmeans(
+        &data,
+        1,
+        TermCriteria::max_iter(1),
+        1,
+        KmeansFlags::RandomCenters,
+    )
+    .is_err());
 }
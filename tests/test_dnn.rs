@@ -43,14 +43,15 @@ fn test_caffe() {
         },
         false,
         false,
-    );
+    )
+    .unwrap();
 
     assert!(!blob.empty());
     net.set_input(&blob, "data").unwrap();
     let prob = net.forward("prob").unwrap();
     assert!(!prob.empty());
-    let prob_mat = prob.reshape(1, 1);
-    let (_, max, min_loc, max_loc) = min_max_loc(&prob_mat);
+    let prob_mat = prob.reshape(1, 1).unwrap();
+    let (_, max, min_loc, max_loc) = min_max_loc(&prob_mat).unwrap();
     assert_eq!((max * 10000.0).round() / 10000.0, 0.9999);
     assert_eq!(min_loc.x, 793);
     assert_eq!(min_loc.y, 0);
@@ -15,6 +15,6 @@ fn test_mser() {
     let image = imread(asset_path("lenna.png"), ImageReadMode::Color).unwrap();
     assert!(!image.empty());
     let mser = MSER::new();
-    let keypoints = mser.detect(&image);
+    let keypoints = mser.detect(&image).unwrap();
     assert_eq!(keypoints.len(), 228);
 }
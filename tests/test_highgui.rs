@@ -4,19 +4,21 @@ use rustcv::highgui::*;
 fn main() {
     let mut window = Window::new("test", WindowFlag::Autosize).expect("failed to create window");
     assert_eq!(window.name(), "test");
-    let val = window.wait_key(1);
+    let val = window.wait_key(1).unwrap();
     assert_eq!(val, -1);
     assert!(window.is_open());
 
-    window.set_property(WindowProperty::Fullscreen, WindowFlag::Normal);
-    let prop = window.get_property(WindowProperty::Fullscreen);
+    window
+        .set_property(WindowProperty::Fullscreen, WindowFlag::Normal)
+        .unwrap();
+    let prop = window.get_property(WindowProperty::Fullscreen).unwrap();
     assert_eq!(prop as i32, WindowFlag::Normal as i32);
     window
         .set_title("My new title")
         .expect("failed to set title");
-    window.move_window(100, 100);
-    window.resize(100, 100);
-    window.close();
+    window.move_window(100, 100).unwrap();
+    window.resize(100, 100).unwrap();
+    window.close().unwrap();
     assert!(!window.is_open());
     ::std::mem::forget(window);
 }
@@ -0,0 +1,99 @@
+#![cfg(feature = "image")]
+
+extern crate image;
+extern crate rustcv;
+
+use image::{GrayImage, ImageBuffer, Luma, Rgb, RgbImage, Rgba, RgbaImage};
+use rustcv::core::*;
+use rustcv::CvError;
+
+#[test]
+fn gray_image() {
+    let image = GrayImage::from_fn(4, 3, |x, y| Luma([(10 * y + x) as u8]));
+    let mat = Mat::from_image(&image, ChannelOrder::Bgr).unwrap();
+    assert_eq!(mat.cv_type(), CvType::Cv8UC1);
+    assert_eq!(mat.rows(), 3);
+    assert_eq!(mat.cols(), 4);
+    assert_eq!(mat.uchar_at(2, 1).unwrap(), 21);
+
+    let back: GrayImage = mat.to_image(ChannelOrder::Bgr).unwrap();
+    assert_eq!(*back, *image);
+}
+
+#[test]
+fn rgb_image() {
+    let image = RgbImage::from_fn(2, 2, |x, y| Rgb([1, x as u8, y as u8]));
+
+    let bgr = Mat::from_image(&image, ChannelOrder::Bgr).unwrap();
+    assert_eq!(bgr.cv_type(), CvType::Cv8UC3);
+
+    let rgb = Mat::from_image(&image, ChannelOrder::Rgb).unwrap();
+    assert_eq!(rgb.data_row(1).unwrap(), &[1, 0, 1, 1, 1, 1][..]);
+    assert_eq!(rgb.data_row(0).unwrap(), &[1, 0, 0, 1, 1, 0][..]);
+    assert_eq!(bgr.data_row(0).unwrap(), &[0, 0, 1, 0, 1, 1][..]);
+
+    let back: RgbImage = bgr.to_image(ChannelOrder::Bgr).unwrap();
+    assert_eq!(*back, *image);
+    let back: RgbImage = rgb.to_image(ChannelOrder::Rgb).unwrap();
+    assert_eq!(*back, *image);
+}
+
+#[test]
+fn rgba_image() {
+    let image = RgbaImage::from_pixel(3, 1, Rgba([10, 20, 30, 40]));
+    let mat = Mat::from_image(&image, ChannelOrder::Bgr).unwrap();
+    assert_eq!(mat.cv_type(), CvType::Cv8UC4);
+    assert_eq!(mat.data_row(0).unwrap()[..4], [30, 20, 10, 40]);
+
+    let back: RgbaImage = mat.to_image(ChannelOrder::Bgr).unwrap();
+    assert_eq!(*back, *image);
+}
+
+#[test]
+fn luma16_and_rgb32f_images() {
+    let image: ImageBuffer<Luma<u16>, Vec<u16>> = ImageBuffer::from_pixel(2, 2, Luma([1000]));
+    let mat = Mat::from_image(&image, ChannelOrder::Bgr).unwrap();
+    assert_eq!(mat.cv_type(), CvType::Cv16UC1);
+    let back: ImageBuffer<Luma<u16>, Vec<u16>> = mat.to_image(ChannelOrder::Bgr).unwrap();
+    assert_eq!(*back, *image);
+
+    let image: ImageBuffer<Rgb<f32>, Vec<f32>> =
+        ImageBuffer::from_pixel(2, 2, Rgb([0.25, 0.5, 0.75]));
+    let mat = Mat::from_image(&image, ChannelOrder::Bgr).unwrap();
+    assert_eq!(mat.cv_type(), CvType::Cv32FC3);
+    assert_eq!(mat.float_at(0, 0).unwrap(), 0.75);
+    assert_eq!(mat.float_at(0, 2).unwrap(), 0.25);
+
+    let back: ImageBuffer<Rgb<f32>, Vec<f32>> = mat.to_image(ChannelOrder::Bgr).unwrap();
+    assert_eq!(*back, *image);
+}
+
+#[test]
+fn image_from_region() {
+    let image = GrayImage::from_fn(5, 5, |x, y| Luma([(10 * y + x) as u8]));
+    let mat = Mat::from_image(&image, ChannelOrder::Bgr).unwrap();
+    let roi = mat
+        .region(Rect {
+            x: 1,
+            y: 2,
+            width: 2,
+            height: 2,
+        })
+        .unwrap();
+
+    let crop: GrayImage = roi.to_image(ChannelOrder::Bgr).unwrap();
+    assert_eq!(crop.into_raw(), vec![21, 22, 31, 32]);
+}
+
+#[test]
+fn image_type_mismatch() {
+    let mat = Mat::new_with_size(2, 2, CvType::Cv8UC3).unwrap();
+    let err = mat.to_image::<Luma<u8>>(ChannelOrder::Bgr).unwrap_err();
+    match err.downcast_ref::<CvError>() {
+        Some(&CvError::TypeMismatch { expected, actual }) => {
+            assert_eq!(expected, CvType::Cv8UC1);
+            assert_eq!(actual, CvType::Cv8UC3);
+        }
+        other => panic!("unexpected error: {:?}", other),
+    }
+}
@@ -29,3 +29,11 @@ fn test_write() {
     assert_eq!(src2.cols(), 512);
     assert_eq!(src2.rows(), 512);
 }
+
+#[test]
+fn test_write_unknown_extension() {
+    let src = imread(asset_path("lenna.png"), ImageReadMode::Color).expect("failed to load lenna");
+    let temp_dir = tempdir::TempDir::new("out").unwrap();
+    let temp_file = temp_dir.path().join("lenna.unknown");
+    assert!(imwrite(&temp_file, &src).is_err());
+}
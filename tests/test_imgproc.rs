@@ -2,6 +2,7 @@ extern crate rustcv;
 use rustcv::core::*;
 use rustcv::imgproc::*;
 use rustcv::imgcodecs::*;
+use rustcv::CvError;
 use std::path::PathBuf;
 
 fn asset_path(f: &str) -> PathBuf {
@@ -29,12 +30,27 @@ fn messi_face() -> Mat {
     load("messi_face.jpg")
 }
 
+#[test]
+fn test_cvt_color_invalid_input() {
+    let src = Mat::new_with_size(10, 10, CvType::Cv8UC1).unwrap();
+    let mut dst = Mat::new();
+
+    let err = cvt_color(&src, &mut dst, ColorConversion::BGR2GRAY).unwrap_err();
+    match err.downcast_ref::<CvError>() {
+        Some(&CvError::OpenCv { code, ref func, .. }) => {
+            assert_ne!(code, 0);
+            assert!(!func.is_empty());
+        }
+        other => panic!("unexpected error: {:?}", other),
+    }
+}
+
 #[test]
 fn test_canny() {
     let src = lenna();
     let mut dst = Mat::new();
 
-    canny(&src, &mut dst, 50.0, 150.0);
+    canny(&src, &mut dst, 50.0, 150.0).unwrap();
     assert!(!dst.empty());
     assert_eq!(src.rows(), dst.rows());
     assert_eq!(src.cols(), dst.cols());
@@ -50,7 +66,7 @@ fn test_gaussian_blur() {
         height: 23,
     };
 
-    gaussian_blur(&src, &mut dst, ksize, 30.0, 50.0, BorderType::Reflect101);
+    gaussian_blur(&src, &mut dst, ksize, 30.0, 50.0, BorderType::Reflect101).unwrap();
     assert!(!dst.empty());
     assert_eq!(src.rows(), dst.rows());
     assert_eq!(src.cols(), dst.cols());
@@ -67,8 +83,9 @@ fn test_match_template() {
         &mut result,
         TemplateMatchMode::CcoeffNormed,
         &Mat::new(),
-    );
-    let (_, max_conf, _, _) = min_max_loc(&result);
+    )
+    .unwrap();
+    let (_, max_conf, _, _) = min_max_loc(&result).unwrap();
     assert!(max_conf > 0.95);
 }
 
@@ -76,7 +93,7 @@ fn test_match_template() {
 fn test_median_blur() {
     let src = lenna();
     let mut dst = Mat::new();
-    median_blur(&src, &mut dst, 1);
+    median_blur(&src, &mut dst, 1).unwrap();
     assert!(!dst.empty());
     assert_eq!(src.rows(), dst.rows());
     assert_eq!(src.cols(), dst.cols());
@@ -90,7 +107,7 @@ fn test_pyr_down() {
         width: dst.cols(),
         height: dst.rows(),
     };
-    pyr_down(&src, &mut dst, size, BorderType::Reflect101);
+    pyr_down(&src, &mut dst, size, BorderType::Reflect101).unwrap();
     assert!(!dst.empty());
     assert!((src.cols() - 2 * dst.cols()).abs() < 2);
     assert!((src.rows() - 2 * dst.rows()).abs() < 2);
@@ -104,7 +121,7 @@ fn test_pyr_up() {
         width: dst.cols(),
         height: dst.rows(),
     };
-    pyr_up(&src, &mut dst, size, BorderType::Reflect101);
+    pyr_up(&src, &mut dst, size, BorderType::Reflect101).unwrap();
     assert!(!dst.empty());
     assert!((2 * src.cols() - dst.cols()).abs() < 2);
     assert!((2 * src.rows() - dst.rows()).abs() < 2);
@@ -126,7 +143,8 @@ fn test_resize() {
         0.5,
         0.5,
         InterpolationFlag::Linear,
-    );
+    )
+    .unwrap();
     assert_eq!(dst.cols(), 256);
     assert_eq!(dst.rows(), 256);
 
@@ -141,7 +159,8 @@ fn test_resize() {
         0.0,
         0.0,
         InterpolationFlag::Cubic,
-    );
+    )
+    .unwrap();
     assert_eq!(dst.cols(), 440);
     assert_eq!(dst.rows(), 377);
 }
@@ -0,0 +1,120 @@
+extern crate rustcv;
+use rustcv::core::linalg::*;
+use rustcv::core::*;
+
+fn mat(rows: i32, cols: i32, values: &[f64]) -> Mat {
+    let mut m = TypedMat::<f64>::new(rows, cols).unwrap();
+    for r in 0..rows {
+        let start = (r * cols) as usize;
+        m.row_mut(r)
+            .unwrap()
+            .copy_from_slice(&values[start..start + cols as usize]);
+    }
+    m.into_mat()
+}
+
+fn values(m: &Mat) -> Vec<f64> {
+    let m = TypedMat::<f64>::from_mat(m.clone()).unwrap();
+    (0..m.rows())
+        .flat_map(|r| m.row(r).unwrap().to_vec())
+        .collect()
+}
+
+fn assert_close(actual: &[f64], expected: &[f64]) {
+    assert_eq!(actual.len(), expected.len());
+    for (a, e) in actual.iter().zip(expected) {
+        assert!((a - e).abs() < 1e-9, "{:?} != {:?}", actual, expected);
+    }
+}
+
+#[test]
+fn test_gemm_and_transpose() {
+    let a = mat(2, 3, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    let c = mat(2, 2, &[1.0, 1.0, 1.0, 1.0]);
+    let mut dst = Mat::new();
+
+    gemm(
+        &a,
+        &a,
+        1.0,
+        Some(&c),
+        2.0,
+        &mut dst,
+        GemmFlags::TRANSPOSE_SRC2,
+    )
+    .unwrap();
+    assert_close(&values(&dst), &[16.0, 34.0, 34.0, 79.0]);
+
+    gemm(&a, &a, 0.5, None, 0.0, &mut dst, GemmFlags::TRANSPOSE_SRC1).unwrap();
+    assert_eq!((dst.rows(), dst.cols()), (3, 3));
+
+    transpose(&a, &mut dst).unwrap();
+    assert_close(&values(&dst), &[1.0, 4.0, 2.0, 5.0, 3.0, 6.0]);
+
+    mul_transposed(&a, &mut dst, false, None, 1.0, None).unwrap();
+    assert_close(&values(&dst), &[14.0, 32.0, 32.0, 77.0]);
+}
+
+#[test]
+fn test_invert_and_solve() {
+    let a = mat(2, 2, &[4.0, 7.0, 2.0, 6.0]);
+    let mut inv = Mat::new();
+    assert!(invert(&a, &mut inv, DecompType::Lu).unwrap() != 0.0);
+    assert_close(&values(&inv), &[0.6, -0.7, -0.2, 0.4]);
+
+    assert!((determinant(&a).unwrap() - 10.0).abs() < 1e-9);
+    assert_eq!(trace(&a).unwrap().val1, 10.0);
+
+    let singular = mat(2, 2, &[1.0, 2.0, 2.0, 4.0]);
+    assert_eq!(invert(&singular, &mut inv, DecompType::Lu).unwrap(), 0.0);
+
+    let b = mat(2, 1, &[1.0, 2.0]);
+    let mut x = Mat::new();
+    assert!(solve(&a, &b, &mut x, DecompType::Lu).unwrap());
+    assert_close(&values(&x), &[-0.8, 0.6]);
+}
+
+#[test]
+fn test_least_squares() {
+    // y = 2x + 1
+    let a = mat(3, 2, &[0.0, 1.0, 1.0, 1.0, 2.0, 1.0]);
+    let b = mat(3, 1, &[1.0, 3.0, 5.0]);
+    let mut x = Mat::new();
+    assert!(solve(&a, &b, &mut x, DecompType::Svd).unwrap());
+    assert_close(&values(&x), &[2.0, 1.0]);
+
+    let svd = Svd::compute(&a, SvdFlags::empty()).unwrap();
+    assert_eq!(svd.w().rows(), 2);
+    svd.back_subst(&b, &mut x).unwrap();
+    assert_close(&values(&x), &[2.0, 1.0]);
+
+    let svd = Svd::compute(&a, SvdFlags::NO_UV).unwrap();
+    assert!(svd.u().empty());
+}
+
+#[test]
+fn test_eigen() {
+    let a = mat(2, 2, &[2.0, 0.0, 0.0, 3.0]);
+    let mut eigenvalues = Mat::new();
+    let mut eigenvectors = Mat::new();
+    assert!(eigen(&a, &mut eigenvalues, &mut eigenvectors).unwrap());
+    assert_close(&values(&eigenvalues), &[3.0, 2.0]);
+    assert_close(
+        &values(&eigenvectors)
+            .iter()
+            .map(|v| v.abs())
+            .collect::<Vec<_>>(),
+        &[0.0, 1.0, 1.0, 0.0],
+    );
+}
+
+#[test]
+fn test_cross_and_dot() {
+    let x = mat(3, 1, &[1.0, 0.0, 0.0]);
+    let y = mat(3, 1, &[0.0, 1.0, 0.0]);
+    let mut z = Mat::new();
+    cross(&x, &y, &mut z).unwrap();
+    assert_close(&values(&z), &[0.0, 0.0, 1.0]);
+    assert_eq!(dot(&x, &y).unwrap(), 0.0);
+    assert_eq!(dot(&x, &x).unwrap(), 1.0);
+}
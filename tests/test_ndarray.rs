@@ -0,0 +1,86 @@
+#![cfg(feature = "ndarray")]
+
+#[macro_use]
+extern crate ndarray;
+extern crate rustcv;
+
+use ndarray::Array3;
+use rustcv::core::*;
+use rustcv::CvError;
+
+#[test]
+fn array_view2() {
+    let mut mat = Mat::new_with_size(2, 3, CvType::Cv32FC1).unwrap();
+    for (i, v) in mat.array_view2_mut::<f32>().unwrap().iter_mut().enumerate() {
+        *v = i as f32;
+    }
+
+    let view = mat.array_view2::<f32>().unwrap();
+    assert_eq!(view.shape(), &[2, 3]);
+    assert_eq!(view[[1, 2]], 5.0);
+    assert_eq!(mat.float_at(1, 0).unwrap(), 3.0);
+
+    assert!(mat.array_view2::<f64>().is_err());
+    let err = Mat::new().array_view2::<f32>().unwrap_err();
+    match err.downcast_ref::<CvError>() {
+        Some(&CvError::EmptyMat) => {}
+        other => panic!("unexpected error: {:?}", other),
+    }
+}
+
+#[test]
+fn array_view3() {
+    let array = Array3::from_shape_fn((4, 5, 3), |(r, c, ch)| (r * 100 + c * 10 + ch) as u8);
+    let mat = Mat::from_array3(&array).unwrap();
+    assert_eq!(mat.cv_type(), CvType::Cv8UC3);
+    assert_eq!(mat.rows(), 4);
+    assert_eq!(mat.cols(), 5);
+
+    let view = mat.array_view3::<u8>().unwrap();
+    assert_eq!(view, array.view());
+    assert_eq!(mat.array_view2::<[u8; 3]>().unwrap()[[3, 4]], [34, 35, 36]);
+    assert!(mat.array_view3::<[u8; 3]>().is_err());
+}
+
+#[test]
+fn array_view_region() {
+    let array = Array3::from_shape_fn((6, 6, 1), |(r, c, _)| (r * 6 + c) as f32);
+    let mat = Mat::from_array3(&array).unwrap();
+    let roi = mat
+        .region(Rect {
+            x: 1,
+            y: 2,
+            width: 3,
+            height: 2,
+        })
+        .unwrap();
+
+    let view = roi.array_view3::<f32>().unwrap();
+    assert_eq!(view, array.slice(s![2..4, 1..4, ..]));
+}
+
+#[test]
+fn empty_array3() {
+    let mat = Mat::from_array3(&Array3::<u8>::zeros((0, 4, 3))).unwrap();
+    assert!(mat.empty());
+    assert_eq!(mat.cv_type(), CvType::Cv8UC3);
+    match mat
+        .array_view3::<f32>()
+        .unwrap_err()
+        .downcast_ref::<CvError>()
+    {
+        Some(&CvError::EmptyMat) => {}
+        other => panic!("unexpected error: {:?}", other),
+    }
+}
+
+#[test]
+fn array3_invalid_channels() {
+    let err = Mat::from_array3(&Array3::<u8>::zeros((2, 2, 0))).unwrap_err();
+    match err.downcast_ref::<CvError>() {
+        Some(&CvError::InvalidChannels(0)) => {}
+        other => panic!("unexpected error: {:?}", other),
+    }
+    assert!(Mat::from_array3(&Array3::<f32>::zeros((1, 1, CvType::CN_MAX as usize + 1))).is_err());
+    assert!(Mat::from_array3(&Array3::<[u8; 3]>::from_elem((1, 1, 1), [0; 3])).is_err());
+}
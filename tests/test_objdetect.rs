@@ -21,10 +21,18 @@ fn test_cascade_classifier() {
         .load(asset_path("haarcascade_frontalface_default.xml"))
         .expect("failed to load cascade model");
 
-    let rects = classifier.detect_multiscale(&image);
+    let rects = classifier.detect_multiscale(&image).unwrap();
     assert_eq!(rects.len(), 1);
     assert_eq!(rects[0].x, 217);
     assert_eq!(rects[0].y, 201);
     assert_eq!(rects[0].width, 173);
     assert_eq!(rects[0].height, 173);
 }
+
+#[test]
+fn test_cascade_classifier_not_loaded() {
+    let image =
+        imread(asset_path("lenna.png"), ImageReadMode::Color).expect("failed to load lenna.png");
+    let classifier = CascadeClassifier::new();
+    assert!(classifier.detect_multiscale(&image).is_err());
+}
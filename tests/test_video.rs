@@ -0,0 +1,272 @@
+extern crate rustcv;
+use rustcv::core::*;
+use rustcv::imgcodecs::*;
+use rustcv::imgproc::*;
+use rustcv::video::*;
+use rustcv::CvError;
+use std::path::PathBuf;
+
+fn asset_path(f: &str) -> PathBuf {
+    let mut d = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    d.push("assets");
+    d.push(f);
+    d
+}
+
+fn lenna() -> Mat {
+    let src = imread(asset_path("lenna.png"), ImageReadMode::Color).expect("failed to load lenna");
+    assert!(!src.empty());
+    src
+}
+
+#[test]
+fn test_mog2() {
+    let src = lenna();
+    let mut mog2 = BackgroundSubtractorMOG2::with_params(100, 16.0, false);
+    let mut mask = Mat::new();
+    for _ in 0..10 {
+        mog2.apply(&src, &mut mask, -1.0).unwrap();
+    }
+    assert_eq!(mask.rows(), src.rows());
+    assert_eq!(mask.cols(), src.cols());
+    assert_eq!(mask.channels(), 1);
+    assert!(count_non_zero(&mask).unwrap() < src.rows() * src.cols() / 100);
+
+    let background = mog2.background_image().unwrap();
+    assert_eq!(background.rows(), src.rows());
+    assert_eq!(background.cols(), src.cols());
+}
+
+#[test]
+fn test_knn() {
+    let src = lenna();
+    let mut knn = BackgroundSubtractorKNN::new();
+    let mut mask = Mat::new();
+    for _ in 0..10 {
+        knn.apply(&src, &mut mask, -1.0).unwrap();
+    }
+    assert_eq!(mask.rows(), src.rows());
+    assert_eq!(mask.cols(), src.cols());
+    assert_eq!(mask.channels(), 1);
+
+    let background = knn.background_image().unwrap();
+    assert!(!background.empty());
+}
+
+fn shifted_pair() -> (Mat, Mat) {
+    let src = lenna();
+    let mut gray = Mat::new();
+    cvt_color(&src, &mut gray, ColorConversion::BGR2GRAY).unwrap();
+    let prev = gray
+        .region(Rect {
+            x: 0,
+            y: 0,
+            width: 500,
+            height: 500,
+        })
+        .unwrap()
+        .clone();
+    let next = gray
+        .region(Rect {
+            x: 2,
+            y: 0,
+            width: 500,
+            height: 500,
+        })
+        .unwrap()
+        .clone();
+    (prev, next)
+}
+
+#[test]
+fn test_calc_optical_flow_pyr_lk() {
+    let (prev, next) = shifted_pair();
+    let mut corners = Mat::new();
+    good_features_to_track(&prev, &mut corners, 50, 0.01, 10.0).unwrap();
+    let prev_pts = corners.to_points2f().unwrap();
+    assert_eq!(prev_pts.len(), 50);
+
+    let win_size = Size {
+        width: 21,
+        height: 21,
+    };
+    let (next_pts, status, err) = calc_optical_flow_pyr_lk(
+        &prev,
+        &next,
+        &prev_pts,
+        None,
+        win_size,
+        3,
+        TermCriteria::max_iter(30).with_eps(0.01),
+        OpticalFlowFlags::empty(),
+        1e-4,
+    )
+    .unwrap();
+    assert_eq!(next_pts.len(), prev_pts.len());
+    assert_eq!(status.len(), prev_pts.len());
+    assert_eq!(err.len(), prev_pts.len());
+
+    let tracked: Vec<_> = (0..prev_pts.len()).filter(|&i| status[i] == 1).collect();
+    assert!(tracked.len() > 40);
+    for i in tracked {
+        assert!((next_pts[i].x - prev_pts[i].x + 2.0).abs() < 0.5);
+        assert!((next_pts[i].y - prev_pts[i].y).abs() < 0.5);
+    }
+}
+
+#[test]
+fn test_calc_optical_flow_pyr_lk_length_mismatch() {
+    let (prev, next) = shifted_pair();
+    let prev_pts = vec![Point2f { x: 10.0, y: 10.0 }; 3];
+    let next_pts = vec![Point2f { x: 10.0, y: 10.0 }; 2];
+    let err = calc_optical_flow_pyr_lk(
+        &prev,
+        &next,
+        &prev_pts,
+        Some(&next_pts),
+        Size {
+            width: 21,
+            height: 21,
+        },
+        3,
+        TermCriteria::max_iter(30),
+        OpticalFlowFlags::USE_INITIAL_FLOW,
+        1e-4,
+    )
+    .unwrap_err();
+    match err.downcast_ref::<CvError>() {
+        Some(&CvError::DimensionMismatch { expected, actual }) => {
+            assert_eq!((expected, actual), (3, 2));
+        }
+        other => panic!("unexpected error: {:?}", other),
+    }
+}
+
+#[test]
+fn test_calc_optical_flow_farneback() {
+    let (prev, next) = shifted_pair();
+    let flow = calc_optical_flow_farneback(
+        &prev,
+        &next,
+        0.5,
+        3,
+        15,
+        3,
+        5,
+        1.2,
+        OpticalFlowFlags::empty(),
+    )
+    .unwrap();
+    assert_eq!(flow.rows(), prev.rows());
+    assert_eq!(flow.cols(), prev.cols());
+    assert_eq!(flow.channels(), 2);
+}
+
+fn mat_from(rows: i32, cols: i32, data: &[f32]) -> Mat {
+    let mut m = Mat::new_with_size(rows, cols, CvType::Cv32FC1).unwrap();
+    for (i, &v) in data.iter().enumerate() {
+        m.set_float_at(i as i32 / cols, i as i32 % cols, v).unwrap();
+    }
+    m
+}
+
+#[test]
+fn test_kalman_filter() {
+    // Constant velocity model: state is (position, velocity).
+    let mut kf = KalmanFilter::new(2, 1, 0, CvType::Cv32FC1).unwrap();
+    kf.set_transition_matrix(&mat_from(2, 2, &[1.0, 1.0, 0.0, 1.0]))
+        .unwrap();
+    kf.set_measurement_matrix(&mat_from(1, 2, &[1.0, 0.0]))
+        .unwrap();
+    kf.set_process_noise_cov(&mat_from(2, 2, &[1e-5, 0.0, 0.0, 1e-5]))
+        .unwrap();
+    kf.set_measurement_noise_cov(&mat_from(1, 1, &[1e-1]))
+        .unwrap();
+    kf.set_error_cov_post(&mat_from(2, 2, &[1.0, 0.0, 0.0, 1.0]))
+        .unwrap();
+    kf.set_state_post(&mat_from(2, 1, &[0.0, 0.0])).unwrap();
+
+    let transition = kf.transition_matrix();
+    assert_eq!(transition.rows(), 2);
+    assert_eq!(transition.cols(), 2);
+    assert_eq!(transition.float_at(0, 1).unwrap(), 1.0);
+
+    for i in 0..50 {
+        let prediction = kf.predict(None).unwrap();
+        assert_eq!(prediction.rows(), 2);
+        kf.correct(&mat_from(1, 1, &[i as f32])).unwrap();
+    }
+
+    let state = kf.state_post();
+    assert!((state.float_at(0, 0).unwrap() - 49.0).abs() < 0.5);
+    assert!((state.float_at(1, 0).unwrap() - 1.0).abs() < 0.1);
+}
+
+#[test]
+fn test_kalman_filter_invalid_params() {
+    assert!(KalmanFilter::new(0, 1, 0, CvType::Cv32FC1).is_err());
+    assert!(KalmanFilter::new(2, 1, 0, CvType::Cv8UC1).is_err());
+}
+
+fn blob_back_projection() -> Mat {
+    let mut prob = Mat::new_with_size(200, 200, CvType::Cv8UC1).unwrap();
+    let black = Scalar {
+        val1: 0.0,
+        val2: 0.0,
+        val3: 0.0,
+        val4: 0.0,
+    };
+    let white = Scalar {
+        val1: 255.0,
+        val2: 255.0,
+        val3: 255.0,
+        val4: 0.0,
+    };
+    let all = Rect {
+        x: 0,
+        y: 0,
+        width: 200,
+        height: 200,
+    };
+    let blob = Rect {
+        x: 120,
+        y: 100,
+        width: 40,
+        height: 40,
+    };
+    rectangle(&mut prob, all, black, -1).unwrap();
+    rectangle(&mut prob, blob, white, -1).unwrap();
+    prob
+}
+
+#[test]
+fn test_mean_shift() {
+    let prob = blob_back_projection();
+    let window = Rect {
+        x: 100,
+        y: 80,
+        width: 40,
+        height: 40,
+    };
+    let window = mean_shift(&prob, window, TermCriteria::new(10, 1.0)).unwrap();
+    assert!((window.x - 120).abs() <= 1);
+    assert!((window.y - 100).abs() <= 1);
+    assert_eq!(window.width, 40);
+    assert_eq!(window.height, 40);
+}
+
+#[test]
+fn test_cam_shift() {
+    let prob = blob_back_projection();
+    let window = Rect {
+        x: 100,
+        y: 80,
+        width: 40,
+        height: 40,
+    };
+    let (rect, window) = cam_shift(&prob, window, TermCriteria::new(10, 1.0)).unwrap();
+    assert!((rect.center.x - 140.0).abs() < 2.0);
+    assert!((rect.center.y - 120.0).abs() < 2.0);
+    assert!(window.x >= 110 && window.x <= 130);
+    assert!(window.y >= 90 && window.y <= 110);
+}
@@ -0,0 +1,132 @@
+extern crate rustcv;
+extern crate tempdir;
+use rustcv::core::*;
+use rustcv::imgcodecs::*;
+use rustcv::videoio::*;
+use rustcv::CvError;
+use std::path::PathBuf;
+
+fn asset_path(f: &str) -> PathBuf {
+    let mut d = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    d.push("assets");
+    d.push(f);
+    d
+}
+
+#[test]
+fn test_capture_invalid_source() {
+    assert!(VideoCapture::open(asset_path("no_such_video.avi")).is_err());
+}
+
+#[test]
+fn test_capture_image_sequence() {
+    let src = imread(asset_path("lenna.png"), ImageReadMode::Color).expect("failed to load lenna");
+    assert!(!src.empty());
+
+    let temp_dir = tempdir::TempDir::new("frames").unwrap();
+    for i in 0..3 {
+        let path = temp_dir.path().join(format!("frame_{:02}.png", i));
+        imwrite(&path, &src).expect("failed to write frame");
+    }
+
+    let mut capture = VideoCapture::open(temp_dir.path().join("frame_%02d.png"))
+        .expect("failed to open sequence");
+    assert!(capture.is_opened());
+    assert_eq!(capture.frame_width(), 512);
+    assert_eq!(capture.frame_height(), 512);
+
+    let mut frame = Mat::new();
+    assert!(capture.read(&mut frame).unwrap());
+    assert_eq!(frame.rows(), 512);
+    assert_eq!(frame.cols(), 512);
+    assert_eq!(capture.position(), 1);
+
+    let rest: Vec<Mat> = capture.map(|f| f.expect("failed to read frame")).collect();
+    assert_eq!(rest.len(), 2);
+    assert!(rest.iter().all(|f| f.cols() == 512 && f.rows() == 512));
+}
+
+#[test]
+fn test_fourcc() {
+    let fourcc: FourCC = "MJPG".parse().expect("failed to parse fourcc");
+    assert_eq!(fourcc, FourCC::MJPG);
+    assert_eq!(fourcc.to_i32(), 0x47504a4d);
+    assert_eq!(fourcc.to_string(), "MJPG");
+    assert!("MJP".parse::<FourCC>().is_err());
+    assert!(FourCC::new(b'M', b'J', b'P', 0).is_err());
+}
+
+#[test]
+fn test_writer_round_trip() {
+    let src = imread(asset_path("lenna.png"), ImageReadMode::Color).expect("failed to load lenna");
+    assert!(!src.empty());
+
+    let size = Size {
+        width: src.cols(),
+        height: src.rows(),
+    };
+    let temp_dir = tempdir::TempDir::new("video").unwrap();
+    let temp_file = temp_dir.path().join("lenna.avi");
+    {
+        let mut writer = VideoWriter::new(&temp_file, FourCC::MJPG, 25.0, size, true)
+            .expect("failed to open writer");
+        assert!(writer.is_opened());
+        for _ in 0..5 {
+            writer.write(&src).expect("failed to write frame");
+        }
+    }
+
+    let capture = VideoCapture::open(&temp_file).expect("failed to open written video");
+    assert_eq!(capture.frame_width(), 512);
+    assert_eq!(capture.frame_height(), 512);
+    assert_eq!(capture.fps(), 25.0);
+    assert_eq!(capture.count(), 5);
+}
+
+#[test]
+fn test_writer_frame_mismatch() {
+    let src = imread(asset_path("lenna.png"), ImageReadMode::Color).expect("failed to load lenna");
+    let gray =
+        imread(asset_path("lenna.png"), ImageReadMode::Grayscale).expect("failed to load lenna");
+
+    let size = Size {
+        width: 256,
+        height: 256,
+    };
+    let temp_dir = tempdir::TempDir::new("video").unwrap();
+    let mut writer = VideoWriter::new(
+        temp_dir.path().join("out.avi"),
+        FourCC::MJPG,
+        25.0,
+        size,
+        true,
+    )
+    .expect("failed to open writer");
+    assert!(writer.write(&src).is_err());
+
+    let size = Size {
+        width: 512,
+        height: 512,
+    };
+    let mut writer = VideoWriter::new(
+        temp_dir.path().join("out2.avi"),
+        FourCC::MJPG,
+        25.0,
+        size,
+        true,
+    )
+    .expect("failed to open writer");
+    assert!(writer.write(&gray).is_err());
+
+    let mut float = Mat::new();
+    src.convert_to(&mut float, CvType::Cv32FC3).unwrap();
+    let err = writer.write(&float).unwrap_err();
+    match err.downcast_ref::<CvError>() {
+        Some(&CvError::TypeMismatch { expected, actual }) => {
+            assert_eq!(expected, CvType::Cv8UC3);
+            assert_eq!(actual, CvType::Cv32FC3);
+        }
+        other => panic!("unexpected error: {:?}", other),
+    }
+    assert!(writer.write(&src).is_ok());
+}